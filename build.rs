@@ -0,0 +1,33 @@
+use flate2::{write::DeflateEncoder, Compression};
+use std::{env, fs, io::Write, path::Path};
+
+/// The directories in the "saves" directory that are embedded into the program.
+const SAVE_FILE_DIRS: [&str; 6] = [
+    "Rebirth",
+    "Afterbirth",
+    "Afterbirth+",
+    "Afterbirth+BP5",
+    "Repentance",
+    "Repentance+",
+];
+
+/// Compresses the save files so that they take up less space in the program.
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("Failed to get the \"OUT_DIR\" variable.");
+
+    for save_file_dir in SAVE_FILE_DIRS {
+        let source_path = format!("saves/{}/persistentgamedata.dat", save_file_dir);
+        println!("cargo:rerun-if-changed={}", source_path);
+
+        let bytes = fs::read(&source_path).expect("Failed to read a save file.");
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        encoder
+            .write_all(&bytes)
+            .expect("Failed to compress a save file.");
+        let compressed_bytes = encoder.finish().expect("Failed to compress a save file.");
+
+        let destination_path = Path::new(&out_dir).join(format!("{}.dat.deflate", save_file_dir));
+        fs::write(destination_path, compressed_bytes)
+            .expect("Failed to write a compressed save file.");
+    }
+}
@@ -0,0 +1,321 @@
+use crate::{
+    enums::IsaacVersion,
+    save_data_path::{get_steam_dir, ISAAC_STEAM_ID},
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use std::{collections::HashMap, fs::read};
+
+pub const ACHIEVEMENTS_URL: &str = "https://bindingofisaacrebirth.fandom.com/wiki/Achievements";
+
+/// The types of the values in the binary "KeyValues" format that Steam uses for its cache files.
+const TYPE_OBJECT: u8 = 0x00;
+const TYPE_STRING: u8 = 0x01;
+const TYPE_INT32: u8 = 0x02;
+const TYPE_FLOAT32: u8 = 0x03;
+const TYPE_POINTER: u8 = 0x04;
+const TYPE_COLOR: u8 = 0x06;
+const TYPE_UINT64: u8 = 0x07;
+const TYPE_END: u8 = 0x08;
+const TYPE_INT64: u8 = 0x0a;
+
+struct Achievement {
+    name: String,
+    /// What Steam shows under the name of the achievement, which is usually how to unlock it.
+    description: String,
+}
+
+/// The names and descriptions of the achievements of a version of the game.
+///
+/// The game does not ship with this text, so it is taken from the copy of the achievement schema
+/// that Steam keeps once the game has been launched. Every version uses the same ID for the same
+/// achievement, so the schema of the newest version also works for the older ones. When the schema
+/// is not available, the table is empty and the achievements are only shown by number.
+pub struct AchievementTable {
+    achievements: HashMap<usize, Achievement>,
+}
+
+impl AchievementTable {
+    pub fn read(isaac_version: IsaacVersion) -> Self {
+        let last_achievement_id = get_last_achievement_id(isaac_version);
+        let achievements = read_achievement_schema()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(id, _)| *id <= last_achievement_id)
+            .collect();
+
+        Self { achievements }
+    }
+
+    pub fn describe(&self, id: usize) -> String {
+        match self.achievements.get(&id) {
+            Some(achievement) if achievement.description.is_empty() => {
+                format!("{}) {}", id, achievement.name)
+            }
+            Some(achievement) => {
+                format!("{}) {} ({})", id, achievement.name, achievement.description)
+            }
+            None => format!("Achievement #{}", id),
+        }
+    }
+}
+
+/// Matches the number of achievements that each version stores in its save files.
+fn get_last_achievement_id(isaac_version: IsaacVersion) -> usize {
+    match isaac_version {
+        IsaacVersion::Rebirth => 178,
+        IsaacVersion::Afterbirth => 276,
+        IsaacVersion::AfterbirthPlus => 348,
+        IsaacVersion::AfterbirthPlusBP5 => 403,
+        IsaacVersion::Repentance => 637,
+        IsaacVersion::RepentancePlus => 641,
+    }
+}
+
+fn get_achievement_schema_path() -> Result<Utf8PathBuf> {
+    let steam_dir = get_steam_dir()?;
+    let schema_path = steam_dir
+        .join("appcache")
+        .join("stats")
+        .join(format!("UserGameStatsSchema_{}.bin", ISAAC_STEAM_ID));
+
+    Ok(schema_path)
+}
+
+fn read_achievement_schema() -> Result<HashMap<usize, Achievement>> {
+    let schema_path = get_achievement_schema_path()?;
+    let bytes = read(&schema_path).context(format!("Failed to read the file: {}", schema_path))?;
+
+    parse_achievement_schema(&bytes)
+}
+
+/// The achievements are the bits of the stats in the schema. The API name of each achievement is
+/// the ID that the game uses in its save files.
+fn parse_achievement_schema(bytes: &[u8]) -> Result<HashMap<usize, Achievement>> {
+    let mut reader = KeyValuesReader { bytes, offset: 0 };
+    let root = reader.read_object()?;
+    let stats = root
+        .get(&ISAAC_STEAM_ID.to_string())
+        .and_then(|game| game.get("stats"))
+        .context("Failed to find the stats in the achievement schema.")?;
+
+    let mut achievements = HashMap::new();
+    for stat in stats.values() {
+        let Some(bits) = stat.get("bits") else {
+            continue;
+        };
+        for bit in bits.values() {
+            let id = bit
+                .get("name")
+                .and_then(KeyValue::as_str)
+                .and_then(|api_name| {
+                    api_name
+                        .trim_start_matches(|c: char| !c.is_ascii_digit())
+                        .parse()
+                        .ok()
+                });
+            let display = bit.get("display");
+            let name = display.and_then(|display| get_english_text(display.get("name")?));
+            if let (Some(id), Some(name)) = (id, name) {
+                let description = display
+                    .and_then(|display| get_english_text(display.get("desc")?))
+                    .unwrap_or_default();
+                achievements.insert(id, Achievement { name, description });
+            }
+        }
+    }
+
+    Ok(achievements)
+}
+
+/// The text is either a plain string or an object with a string for each language.
+fn get_english_text(value: &KeyValue) -> Option<String> {
+    let text = match value {
+        KeyValue::String(text) => text,
+        KeyValue::Object(_) => value.get("english")?.as_str()?,
+        KeyValue::Other => return None,
+    };
+
+    Some(text.to_string())
+}
+
+enum KeyValue {
+    Object(Vec<(String, KeyValue)>),
+    String(String),
+    /// A number, which is not needed for the achievements.
+    Other,
+}
+
+impl KeyValue {
+    fn get(&self, key: &str) -> Option<&KeyValue> {
+        match self {
+            KeyValue::Object(entries) => entries
+                .iter()
+                .find(|(entry_key, _)| entry_key == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    fn values(&self) -> impl Iterator<Item = &KeyValue> {
+        let entries = match self {
+            KeyValue::Object(entries) => entries.as_slice(),
+            _ => &[],
+        };
+
+        entries.iter().map(|(_, value)| value)
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            KeyValue::String(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+struct KeyValuesReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl KeyValuesReader<'_> {
+    /// Reads entries until the end of the current object (or of the file, for the root object).
+    fn read_object(&mut self) -> Result<KeyValue> {
+        let mut entries = Vec::new();
+        while self.offset < self.bytes.len() {
+            let type_offset = self.offset;
+            let value_type = self.read_bytes(1)?[0];
+            if value_type == TYPE_END {
+                break;
+            }
+
+            let key = self.read_string()?;
+            let value = match value_type {
+                TYPE_OBJECT => self.read_object()?,
+                TYPE_STRING => KeyValue::String(self.read_string()?),
+                TYPE_INT32 | TYPE_FLOAT32 | TYPE_POINTER | TYPE_COLOR => {
+                    self.read_bytes(4)?;
+                    KeyValue::Other
+                }
+                TYPE_UINT64 | TYPE_INT64 => {
+                    self.read_bytes(8)?;
+                    KeyValue::Other
+                }
+                _ => bail!(
+                    "Unknown value type {} at offset {} in the achievement schema.",
+                    value_type,
+                    type_offset,
+                ),
+            };
+            entries.push((key, value));
+        }
+
+        Ok(KeyValue::Object(entries))
+    }
+
+    fn read_bytes(&mut self, length: usize) -> Result<&[u8]> {
+        let bytes = self
+            .bytes
+            .get(self.offset..self.offset + length)
+            .context("The achievement schema ended unexpectedly.")?;
+        self.offset += length;
+
+        Ok(bytes)
+    }
+
+    fn read_string(&mut self) -> Result<String> {
+        let length = self.bytes[self.offset..]
+            .iter()
+            .position(|byte| *byte == 0)
+            .context("The achievement schema ended in the middle of a string.")?;
+        let string = String::from_utf8_lossy(self.read_bytes(length)?).into_owned();
+        self.read_bytes(1)?;
+
+        Ok(string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::FakeTree;
+    use std::fs::{create_dir_all, write};
+
+    /// A schema in the same layout as the one that Steam writes, with a number in between to check
+    /// that it is skipped. It is written to the Steam directory of the current fake tree.
+    fn write_achievement_schema(achievements: &[(usize, &str, &str)]) {
+        fn push_key(bytes: &mut Vec<u8>, value_type: u8, key: &str) {
+            bytes.push(value_type);
+            bytes.extend_from_slice(key.as_bytes());
+            bytes.push(0);
+        }
+        fn push_string(bytes: &mut Vec<u8>, key: &str, value: &str) {
+            push_key(bytes, TYPE_STRING, key);
+            bytes.extend_from_slice(value.as_bytes());
+            bytes.push(0);
+        }
+
+        let mut bytes = Vec::new();
+        push_key(&mut bytes, TYPE_OBJECT, &ISAAC_STEAM_ID.to_string());
+        push_key(&mut bytes, TYPE_OBJECT, "stats");
+        push_key(&mut bytes, TYPE_OBJECT, "1");
+        push_key(&mut bytes, TYPE_INT32, "type");
+        bytes.extend_from_slice(&4_i32.to_le_bytes());
+        push_key(&mut bytes, TYPE_OBJECT, "bits");
+        for (bit, (id, name, description)) in achievements.iter().enumerate() {
+            push_key(&mut bytes, TYPE_OBJECT, &bit.to_string());
+            push_string(&mut bytes, "name", &id.to_string());
+            push_key(&mut bytes, TYPE_OBJECT, "display");
+            push_key(&mut bytes, TYPE_OBJECT, "name");
+            push_string(&mut bytes, "english", name);
+            bytes.push(TYPE_END);
+            push_key(&mut bytes, TYPE_OBJECT, "desc");
+            push_string(&mut bytes, "english", description);
+            bytes.push(TYPE_END);
+            bytes.push(TYPE_END);
+            bytes.push(TYPE_END);
+        }
+        bytes.extend_from_slice(&[TYPE_END; 5]);
+
+        let schema_path = get_achievement_schema_path().unwrap();
+        create_dir_all(schema_path.parent().unwrap()).unwrap();
+        write(&schema_path, bytes).unwrap();
+    }
+
+    #[test]
+    fn reads_the_names_from_the_steam_schema() {
+        let _fake_tree = FakeTree::new();
+        write_achievement_schema(&[
+            (1, "Magdalene", "Have 7 or more red heart containers"),
+            (179, "Maggy's Faith", ""),
+        ]);
+
+        let table = AchievementTable::read(IsaacVersion::Afterbirth);
+        assert_eq!(
+            table.describe(1),
+            "1) Magdalene (Have 7 or more red heart containers)"
+        );
+        assert_eq!(table.describe(179), "179) Maggy's Faith");
+        assert_eq!(table.describe(2), "Achievement #2");
+
+        // Rebirth does not have the achievements that Afterbirth added.
+        let table = AchievementTable::read(IsaacVersion::Rebirth);
+        assert_eq!(table.describe(179), "Achievement #179");
+    }
+
+    #[test]
+    fn falls_back_to_numbers_without_the_steam_schema() {
+        let _fake_tree = FakeTree::new();
+
+        let table = AchievementTable::read(IsaacVersion::Repentance);
+        assert!(table.achievements.is_empty());
+        assert_eq!(table.describe(1), "Achievement #1");
+    }
+
+    #[test]
+    fn refuses_a_truncated_schema() {
+        assert!(parse_achievement_schema(&[TYPE_OBJECT, b'2']).is_err());
+        assert!(parse_achievement_schema(&[0x0b, b'a', 0]).is_err());
+    }
+}
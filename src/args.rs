@@ -0,0 +1,109 @@
+use anyhow::{bail, Result};
+use camino::Utf8PathBuf;
+use lexopt::prelude::*;
+
+const USAGE: &str = "Usage: isaac-save-installer [history | watch | backups [list | prune | label <hash> [<label>]]] [--keep-last <n>] [--keep-daily <days|all>] [--keep-weekly <weeks|all>] [--no-tui] [--dry-run] [--answers <file>] [--root <dir>] [--documents-dir <dir>] [--steam-dir <dir>] [--steam-user <id>]";
+
+pub enum Command {
+    /// Show what this program has done to the save files.
+    History,
+    /// Back up the save files every time that the game is closed.
+    Watch,
+    Backups(BackupsCommand),
+}
+
+pub enum BackupsCommand {
+    List,
+    /// Remove the backups that the retention policy does not keep.
+    Prune,
+    /// The hash can be shortened, as long as it only matches one backup. An empty label removes the
+    /// label.
+    Label {
+        hash: String,
+        label: String,
+    },
+}
+
+#[derive(Default)]
+pub struct Args {
+    /// `None` runs the interactive interface.
+    pub command: Option<Command>,
+    /// Use the numbered prompts instead of the full-screen interface.
+    pub no_tui: bool,
+    /// Show what would be changed without changing any files.
+    pub dry_run: bool,
+    /// A file with one answer on each line that is used instead of asking the user.
+    pub answers_path: Option<Utf8PathBuf>,
+    /// A directory that every path and registry lookup is done inside of, instead of on the real
+    /// system.
+    pub root: Option<Utf8PathBuf>,
+    /// The directory that contains the "My Games" directory.
+    pub documents_dir: Option<Utf8PathBuf>,
+    /// The directory that Steam is installed in.
+    pub steam_dir: Option<Utf8PathBuf>,
+    pub steam_user_id: Option<u32>,
+    /// These override the retention policy from the config file. `Some(None)` keeps a backup from
+    /// every day or week forever.
+    pub keep_last: Option<usize>,
+    pub keep_daily: Option<Option<u64>>,
+    pub keep_weekly: Option<Option<u64>>,
+}
+
+pub fn parse_args() -> Result<Args> {
+    let mut args = Args::default();
+    let mut parser = lexopt::Parser::from_env();
+    let mut values: Vec<String> = Vec::new();
+    while let Some(arg) = parser.next()? {
+        match arg {
+            Long("no-tui") => args.no_tui = true,
+            Long("dry-run") => args.dry_run = true,
+            Long("answers") => args.answers_path = Some(parser.value()?.string()?.into()),
+            Long("root") => args.root = Some(parser.value()?.string()?.into()),
+            Long("documents-dir") => {
+                args.documents_dir = Some(parser.value()?.string()?.into());
+            }
+            Long("steam-dir") => args.steam_dir = Some(parser.value()?.string()?.into()),
+            Long("steam-user") => args.steam_user_id = Some(parser.value()?.parse()?),
+            Long("keep-last") => args.keep_last = Some(parser.value()?.parse()?),
+            Long("keep-daily") => args.keep_daily = Some(parse_limit(parser.value()?.string()?)?),
+            Long("keep-weekly") => {
+                args.keep_weekly = Some(parse_limit(parser.value()?.string()?)?);
+            }
+            Value(value) => values.push(value.string()?),
+            Short('h') | Long("help") => {
+                println!("{}", USAGE);
+                std::process::exit(0);
+            }
+            _ => bail!("{}\n{}", arg.unexpected(), USAGE),
+        }
+    }
+    args.command = parse_command(&values)?;
+
+    Ok(args)
+}
+
+fn parse_command(values: &[String]) -> Result<Option<Command>> {
+    let values: Vec<&str> = values.iter().map(String::as_str).collect();
+    let command = match values.as_slice() {
+        [] => return Ok(None),
+        ["history"] => Command::History,
+        ["watch"] => Command::Watch,
+        ["backups"] | ["backups", "list"] => Command::Backups(BackupsCommand::List),
+        ["backups", "prune"] => Command::Backups(BackupsCommand::Prune),
+        ["backups", "label", hash, label @ ..] => Command::Backups(BackupsCommand::Label {
+            hash: hash.to_string(),
+            label: label.join(" "),
+        }),
+        _ => bail!("Unknown command: {}\n{}", values.join(" "), USAGE),
+    };
+
+    Ok(Some(command))
+}
+
+/// "all" means that there is no limit.
+fn parse_limit(value: String) -> Result<Option<u64>> {
+    match value.as_str() {
+        "all" => Ok(None),
+        limit => Ok(Some(limit.parse()?)),
+    }
+}
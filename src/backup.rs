@@ -1,46 +1,62 @@
 use anyhow::{bail, Context, Result};
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use colored::*;
-use std::fs::copy;
 
-use crate::utils::get_dir_of_running_exe;
+use crate::{
+    backup_store::{get_backup_object_path, store_backup},
+    dry_run::get_success_prefix,
+    errors::{ErrorKind, InstallerError},
+    history::get_file_hash,
+};
 
 pub fn backup(
     (existing_save_file_path, exists): &(Utf8PathBuf, bool),
     save_file_slot: usize,
 ) -> Result<()> {
     if !exists {
-        bail!(
-            "You cannot backup a save file for slot {} since the corresponding file does not exist.",
-            save_file_slot,
-        );
+        bail!(InstallerError::new(
+            ErrorKind::SlotEmpty,
+            format!(
+                "You cannot backup a save file for slot {} since the corresponding file does not exist.",
+                save_file_slot,
+            ),
+        ));
     }
 
-    let dir_path = get_dir_of_running_exe()?;
-    let file_name = existing_save_file_path.file_name().context(format!(
-        "Failed to get the file name from the path of:\n{}",
-        existing_save_file_path.to_string().green(),
-    ))?;
-    let destination_path = dir_path.join(file_name);
-
-    if destination_path.exists() {
-        bail!(
-            "You cannot backup that save file because the following file already exists in the directory next to this program:\n{}",
-            destination_path.to_string().green(),
-        )
-    }
+    let destination_path = backup_save_file(existing_save_file_path)?;
 
-    copy(existing_save_file_path, &destination_path).context(format!(
-        "Failed to copy:\n{}\n-->\n{}",
+    println!(
+        "{}:\n{}\n-->\n{}",
+        get_success_prefix("backed up", "back up"),
         existing_save_file_path.to_string().green(),
         destination_path.to_string().green(),
-    ))?;
+    );
+
+    Ok(())
+}
+
+/// Adds the save file to the backup store and returns the path of the backup. If the save file has
+/// not changed since its last backup, the last backup is used instead of making a new one.
+pub fn backup_save_file(save_file_path: &Utf8Path) -> Result<Utf8PathBuf> {
+    let hash = match store_backup(save_file_path)? {
+        Some(backup_entry) => backup_entry.hash,
+        None => get_file_hash(save_file_path).context(format!(
+            "Failed to read the file:\n{}",
+            save_file_path.to_string().green(),
+        ))?,
+    };
+
+    get_backup_object_path(&hash)
+}
+
+pub fn backup_before_overwrite(save_file_path: &Utf8Path) -> Result<Utf8PathBuf> {
+    let destination_path = backup_save_file(save_file_path)?;
 
     println!(
-        "Successfully copied:\n{}\n-->\n{}",
-        existing_save_file_path.to_string().green(),
+        "Backed up the save file that will be overwritten:\n{}\n-->\n{}",
+        save_file_path.to_string().green(),
         destination_path.to_string().green(),
     );
 
-    Ok(())
+    Ok(destination_path)
 }
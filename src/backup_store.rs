@@ -0,0 +1,599 @@
+use crate::{
+    config::get_config_dir,
+    constants::{BACKUPS_DIR_NAME, BACKUP_INDEX_FILE_NAME, BACKUP_OBJECTS_DIR_NAME},
+    dry_run::{delete_file, is_dry_run, print_dry_run_actions, write_file},
+    enums::{IsaacVersion, Operation},
+    history::{
+        format_timestamp, get_hash, get_save_file_slot, get_timestamp, record_operation, FileState,
+    },
+    persistent_game_data::PersistentGameData,
+};
+use anyhow::{bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeSet,
+    fmt::Display,
+    fs::{create_dir_all, read, read_to_string},
+};
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+const SHORT_HASH_LEN: usize = 8;
+
+/// One backup in the index. The contents of the backup are stored separately and named after their
+/// hash, so that identical backups are only stored once.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct BackupEntry {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub isaac_version: Option<IsaacVersion>,
+    pub save_file_slot: Option<usize>,
+    pub source_path: Utf8PathBuf,
+    pub hash: String,
+    /// Backups with a label are never pruned.
+    pub label: Option<String>,
+}
+
+/// Which backups are kept when the backups are pruned. Each rule is applied to the backups of each
+/// save file separately, and a backup is kept if any of the rules keeps it.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RetentionPolicy {
+    /// The number of newest backups to keep.
+    pub keep_last: usize,
+    /// The number of days to keep the newest backup of each day for, or `None` for forever.
+    pub keep_daily: Option<u64>,
+    /// The number of weeks to keep the newest backup of each week for, or `None` for forever.
+    pub keep_weekly: Option<u64>,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            keep_last: 20,
+            keep_daily: Some(30),
+            keep_weekly: None,
+        }
+    }
+}
+
+impl Display for RetentionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "the last {} backups, one backup a day {}, and one backup a week {}",
+            self.keep_last,
+            format_limit(self.keep_daily, "day"),
+            format_limit(self.keep_weekly, "week"),
+        )
+    }
+}
+
+fn format_limit(limit: Option<u64>, unit: &str) -> String {
+    match limit {
+        None => "forever".to_string(),
+        Some(1) => format!("for 1 {}", unit),
+        Some(limit) => format!("for {} {}s", limit, unit),
+    }
+}
+
+pub fn get_backups_dir() -> Result<Utf8PathBuf> {
+    Ok(get_config_dir()?.join(BACKUPS_DIR_NAME))
+}
+
+fn get_backup_index_path() -> Result<Utf8PathBuf> {
+    Ok(get_backups_dir()?.join(BACKUP_INDEX_FILE_NAME))
+}
+
+pub fn get_backup_object_path(hash: &str) -> Result<Utf8PathBuf> {
+    Ok(get_backups_dir()?.join(BACKUP_OBJECTS_DIR_NAME).join(hash))
+}
+
+/// The backups are in the order that they were made.
+pub fn read_backup_index() -> Result<Vec<BackupEntry>> {
+    let index_path = get_backup_index_path()?;
+    if !index_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let index_json = read_to_string(&index_path).context(format!(
+        "Failed to read the file:\n{}",
+        index_path.to_string().green(),
+    ))?;
+    serde_json::from_str(&index_json).context(format!(
+        "Failed to parse the file:\n{}",
+        index_path.to_string().green(),
+    ))
+}
+
+pub fn write_backup_index(backup_entries: &[BackupEntry]) -> Result<()> {
+    let index_path = get_backup_index_path()?;
+    create_parent_dir(&index_path)?;
+
+    let index_json = serde_json::to_string_pretty(backup_entries)
+        .context("Failed to serialize the backup index.")?;
+    write_file(&index_path, index_json)
+}
+
+fn create_parent_dir(path: &Utf8Path) -> Result<()> {
+    match path.parent() {
+        Some(dir) if !is_dry_run() => create_dir_all(dir).context(format!(
+            "Failed to create the following directory:\n{}",
+            dir.to_string().green(),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Returns `None` if the file has not changed since the last time that it was backed up.
+pub fn store_backup(save_file_path: &Utf8Path) -> Result<Option<BackupEntry>> {
+    let bytes = read(save_file_path).context(format!(
+        "Failed to read the file:\n{}",
+        save_file_path.to_string().green(),
+    ))?;
+    let hash = get_hash(&bytes);
+
+    let mut backup_entries = read_backup_index()?;
+    let last_backup_entry = get_newest_backup_entry(&backup_entries, save_file_path);
+    if last_backup_entry.is_some_and(|backup_entry| backup_entry.hash == hash) {
+        return Ok(None);
+    }
+
+    let object_path = get_backup_object_path(&hash)?;
+    if !object_path.exists() {
+        let state_before = FileState::read_without_snapshot(&object_path);
+        create_parent_dir(&object_path)?;
+        write_file(&object_path, &bytes)?;
+        record_operation(
+            Operation::Backup,
+            Some(save_file_path),
+            &object_path,
+            state_before,
+        )?;
+    }
+
+    let backup_entry = BackupEntry {
+        timestamp: get_timestamp()?,
+        isaac_version: PersistentGameData::from_bytes(&bytes)
+            .ok()
+            .and_then(|persistent_game_data| persistent_game_data.get_isaac_version()),
+        save_file_slot: get_save_file_slot(save_file_path),
+        source_path: save_file_path.to_path_buf(),
+        hash,
+        label: None,
+    };
+    backup_entries.push(backup_entry.clone());
+    write_backup_index(&backup_entries)?;
+
+    Ok(Some(backup_entry))
+}
+
+/// The backups are in the index in the order that they were made.
+pub fn get_newest_backup_entry<'a>(
+    backup_entries: &'a [BackupEntry],
+    save_file_path: &Utf8Path,
+) -> Option<&'a BackupEntry> {
+    backup_entries
+        .iter()
+        .rev()
+        .find(|backup_entry| backup_entry.source_path == save_file_path)
+}
+
+/// Returns the backups that were removed. The contents of a backup are only deleted once no other
+/// backup uses them.
+pub fn prune_backups(retention_policy: &RetentionPolicy, now: u64) -> Result<Vec<BackupEntry>> {
+    let backup_entries = read_backup_index()?;
+
+    let mut kept = Vec::new();
+    let mut removed = Vec::new();
+    for (i, backup_entry) in backup_entries.iter().enumerate() {
+        let newer_backup_entries: Vec<&BackupEntry> = backup_entries[i + 1..]
+            .iter()
+            .filter(|other| other.source_path == backup_entry.source_path)
+            .collect();
+        match is_kept(backup_entry, &newer_backup_entries, retention_policy, now) {
+            true => kept.push(backup_entry.clone()),
+            false => removed.push(backup_entry.clone()),
+        }
+    }
+    if removed.is_empty() {
+        return Ok(removed);
+    }
+
+    write_backup_index(&kept)?;
+
+    let kept_hashes: BTreeSet<&str> = kept.iter().map(|entry| entry.hash.as_str()).collect();
+    let mut removed_hashes = BTreeSet::new();
+    for backup_entry in &removed {
+        let hash = backup_entry.hash.as_str();
+        if kept_hashes.contains(hash) || !removed_hashes.insert(hash) {
+            continue;
+        }
+
+        let object_path = get_backup_object_path(hash)?;
+        if object_path.exists() {
+            let state_before = FileState::read_without_snapshot(&object_path);
+            delete_file(&object_path)?;
+            record_operation(
+                Operation::Prune,
+                Some(&backup_entry.source_path),
+                &object_path,
+                state_before,
+            )?;
+        }
+    }
+
+    Ok(removed)
+}
+
+fn is_kept(
+    backup_entry: &BackupEntry,
+    newer_backup_entries: &[&BackupEntry],
+    retention_policy: &RetentionPolicy,
+    now: u64,
+) -> bool {
+    if backup_entry.label.is_some() || newer_backup_entries.len() < retention_policy.keep_last {
+        return true;
+    }
+
+    let day = get_day(backup_entry.timestamp);
+    let week = get_week(backup_entry.timestamp);
+    let is_newest_of_day = newer_backup_entries
+        .iter()
+        .all(|other| get_day(other.timestamp) != day);
+    let is_newest_of_week = newer_backup_entries
+        .iter()
+        .all(|other| get_week(other.timestamp) != week);
+
+    (is_newest_of_day && is_within_limit(retention_policy.keep_daily, get_day(now), day))
+        || (is_newest_of_week && is_within_limit(retention_policy.keep_weekly, get_week(now), week))
+}
+
+/// Days and weeks are counted in UTC.
+fn get_day(timestamp: u64) -> u64 {
+    timestamp / SECONDS_PER_DAY
+}
+
+/// Weeks start on Monday. The Unix epoch was on a Thursday.
+fn get_week(timestamp: u64) -> u64 {
+    (get_day(timestamp) + 3) / 7
+}
+
+fn is_within_limit(limit: Option<u64>, current_period: u64, period: u64) -> bool {
+    match limit {
+        None => true,
+        Some(limit) => current_period.saturating_sub(period) < limit,
+    }
+}
+
+pub fn list_backups() -> Result<()> {
+    let backup_entries = read_backup_index()?;
+    if backup_entries.is_empty() {
+        println!("There are no backups yet.");
+        return Ok(());
+    }
+
+    for backup_entry in &backup_entries {
+        print_backup_entry(backup_entry)?;
+    }
+    println!("The backups are in the following directory:");
+    println!("{}", get_backups_dir()?.to_string().green());
+
+    Ok(())
+}
+
+fn print_backup_entry(backup_entry: &BackupEntry) -> Result<()> {
+    let mut heading = vec![
+        get_short_hash(&backup_entry.hash).to_string(),
+        format_timestamp(backup_entry.timestamp),
+    ];
+    if let Some(isaac_version) = backup_entry.isaac_version {
+        heading.push(isaac_version.to_string());
+    }
+    if let Some(save_file_slot) = backup_entry.save_file_slot {
+        heading.push(format!("slot {}", save_file_slot));
+    }
+    if let Some(label) = &backup_entry.label {
+        heading.push(format!("\"{}\"", label));
+    }
+
+    println!("{}", heading.join(" - "));
+    println!("  {}", backup_entry.source_path);
+    println!("  {}", get_backup_object_path(&backup_entry.hash)?);
+    println!();
+
+    Ok(())
+}
+
+pub fn prune(retention_policy: &RetentionPolicy) -> Result<()> {
+    let removed = prune_backups(retention_policy, get_timestamp()?)?;
+    match removed.len() {
+        0 => println!("There are no backups to remove."),
+        1 => println!("Removed 1 backup."),
+        num_removed => println!("Removed {} backups.", num_removed),
+    }
+    println!("The backups that are kept are {}.", retention_policy);
+    print_dry_run_actions();
+
+    Ok(())
+}
+
+/// Like Git, the list of backups only shows the start of each hash.
+fn get_short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(SHORT_HASH_LEN)]
+}
+
+/// The backup is given by its hash, or by the start of it, as shown in the list of backups. Unlike
+/// a position in the list, the hash does not change when other backups are pruned. Backups with the
+/// same contents share a hash, so they all get the label. An empty label removes the label.
+pub fn label_backup(hash: &str, label: &str) -> Result<()> {
+    let hash_prefix = hash.trim().to_lowercase();
+    let mut backup_entries = read_backup_index()?;
+    let mut matching_hashes = backup_entries
+        .iter()
+        .map(|backup_entry| backup_entry.hash.clone())
+        .filter(|hash| !hash_prefix.is_empty() && hash.starts_with(&hash_prefix))
+        .collect::<BTreeSet<String>>()
+        .into_iter();
+    let hash = match (matching_hashes.next(), matching_hashes.next()) {
+        (Some(hash), None) => hash,
+        (None, _) => bail!(
+            "There is no backup with the hash \"{}\". Run \"isaac-save-installer backups\" to see the backups.",
+            hash,
+        ),
+        (Some(_), Some(_)) => bail!(
+            "More than one backup has a hash that starts with \"{}\". Type more of the hash.",
+            hash,
+        ),
+    };
+
+    let label = match label.trim() {
+        "" => None,
+        label => Some(label.to_string()),
+    };
+    for backup_entry in &mut backup_entries {
+        if backup_entry.hash == hash {
+            backup_entry.label.clone_from(&label);
+        }
+    }
+    let message = match &label {
+        Some(label) => format!("Labeled backup {} as \"{}\".", get_short_hash(&hash), label),
+        None => format!("Removed the label of backup {}.", get_short_hash(&hash)),
+    };
+    write_backup_index(&backup_entries)?;
+
+    println!("{}", message);
+    print_dry_run_actions();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{history::read_history, test_utils::FakeTree, undo::get_operation_to_undo};
+    use std::fs::write;
+
+    const DAY: u64 = SECONDS_PER_DAY;
+
+    fn get_backup_entry(timestamp: u64) -> BackupEntry {
+        BackupEntry {
+            timestamp,
+            isaac_version: Some(IsaacVersion::Repentance),
+            save_file_slot: Some(1),
+            source_path: Utf8PathBuf::from("persistentgamedata1.dat"),
+            hash: timestamp.to_string(),
+            label: None,
+        }
+    }
+
+    #[test]
+    fn weeks_start_on_monday() {
+        // Day 3 is a Sunday and day 4 is a Monday.
+        assert_eq!(get_week(0), 0);
+        assert_eq!(get_week(4 * DAY - 1), 0);
+        assert_eq!(get_week(4 * DAY), 1);
+        assert_eq!(get_week(11 * DAY - 1), 1);
+        assert_eq!(get_week(11 * DAY), 2);
+    }
+
+    #[test]
+    fn keeps_the_newest_backups() {
+        let retention_policy = RetentionPolicy {
+            keep_last: 2,
+            keep_daily: Some(0),
+            keep_weekly: Some(0),
+        };
+        let backup_entries: Vec<BackupEntry> = (0..3).map(get_backup_entry).collect();
+        let now = 100 * DAY;
+
+        assert!(!is_kept(
+            &backup_entries[0],
+            &[&backup_entries[1], &backup_entries[2]],
+            &retention_policy,
+            now,
+        ));
+        assert!(is_kept(
+            &backup_entries[1],
+            &[&backup_entries[2]],
+            &retention_policy,
+            now,
+        ));
+    }
+
+    #[test]
+    fn keeps_the_newest_backup_of_each_day() {
+        let retention_policy = RetentionPolicy {
+            keep_last: 0,
+            keep_daily: Some(2),
+            keep_weekly: Some(0),
+        };
+        let old = get_backup_entry(8 * DAY);
+        let yesterday = get_backup_entry(9 * DAY);
+        let today = get_backup_entry(10 * DAY);
+        let later_today = get_backup_entry(10 * DAY + 1);
+        let now = 10 * DAY + 2;
+
+        assert!(!is_kept(
+            &old,
+            &[&yesterday, &today, &later_today],
+            &retention_policy,
+            now,
+        ));
+        assert!(is_kept(
+            &yesterday,
+            &[&today, &later_today],
+            &retention_policy,
+            now,
+        ));
+        assert!(!is_kept(&today, &[&later_today], &retention_policy, now));
+        assert!(is_kept(&later_today, &[], &retention_policy, now));
+    }
+
+    #[test]
+    fn keeps_the_newest_backup_of_each_week_forever() {
+        let retention_policy = RetentionPolicy {
+            keep_last: 0,
+            keep_daily: Some(0),
+            keep_weekly: None,
+        };
+        let sunday = get_backup_entry(3 * DAY);
+        let monday = get_backup_entry(4 * DAY);
+        let tuesday = get_backup_entry(5 * DAY);
+        let now = 1000 * DAY;
+
+        assert!(is_kept(
+            &sunday,
+            &[&monday, &tuesday],
+            &retention_policy,
+            now,
+        ));
+        assert!(!is_kept(&monday, &[&tuesday], &retention_policy, now));
+    }
+
+    #[test]
+    fn keeps_labeled_backups() {
+        let retention_policy = RetentionPolicy {
+            keep_last: 0,
+            keep_daily: Some(0),
+            keep_weekly: Some(0),
+        };
+        let mut backup_entry = get_backup_entry(0);
+        backup_entry.label = Some("before the rollback".to_string());
+        let newer = get_backup_entry(DAY);
+
+        assert!(is_kept(
+            &backup_entry,
+            &[&newer],
+            &retention_policy,
+            100 * DAY
+        ));
+    }
+
+    #[test]
+    fn labels_backups_by_hash() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        for i in 0..3 {
+            write(&save_file_path, format!("session {}", i)).unwrap();
+            store_backup(&save_file_path).unwrap();
+        }
+        let hash = get_hash(b"session 1");
+
+        assert!(label_backup("", "no hash").is_err());
+        assert!(label_backup("not a hash", "unknown").is_err());
+        label_backup(&hash[..4].to_uppercase(), "the middle one").unwrap();
+
+        // The label stays with the same backup after older backups are pruned.
+        let mut backup_entries = read_backup_index().unwrap();
+        backup_entries.remove(0);
+        write_backup_index(&backup_entries).unwrap();
+        label_backup(get_short_hash(&hash), "still the middle one").unwrap();
+
+        let backup_entries = read_backup_index().unwrap();
+        assert_eq!(backup_entries[0].hash, hash);
+        assert_eq!(
+            backup_entries[0].label.as_deref(),
+            Some("still the middle one"),
+        );
+        assert_eq!(backup_entries[1].label, None);
+    }
+
+    #[test]
+    fn prunes_backups_by_retention_policy() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        for i in 0..5 {
+            write(&save_file_path, format!("session {}", i)).unwrap();
+            store_backup(&save_file_path).unwrap();
+        }
+
+        // Day 0 is a Thursday, so days 0 and 1 are in the same week.
+        let timestamps = [0, DAY, 95 * DAY, 95 * DAY + 1, 99 * DAY];
+        let mut backup_entries = read_backup_index().unwrap();
+        for (backup_entry, timestamp) in backup_entries.iter_mut().zip(timestamps) {
+            backup_entry.timestamp = timestamp;
+        }
+        write_backup_index(&backup_entries).unwrap();
+        label_backup(
+            get_short_hash(&backup_entries[2].hash),
+            "before the rollback",
+        )
+        .unwrap();
+
+        let retention_policy = RetentionPolicy {
+            keep_last: 1,
+            keep_daily: Some(30),
+            keep_weekly: None,
+        };
+        let removed = prune_backups(&retention_policy, 100 * DAY).unwrap();
+
+        // The first backup is not the newest of its week, and it is too old to be kept as a daily
+        // backup. The third backup is not the newest of its day, but it has a label.
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].hash, get_hash(b"session 0"));
+        assert!(!get_backup_object_path(&removed[0].hash).unwrap().exists());
+        let backup_entries = read_backup_index().unwrap();
+        assert_eq!(backup_entries.len(), 4);
+        assert_eq!(
+            backup_entries[1].label.as_deref(),
+            Some("before the rollback"),
+        );
+    }
+
+    #[test]
+    fn records_backups_and_prunes_in_the_history() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        write(&save_file_path, "session 0").unwrap();
+        store_backup(&save_file_path).unwrap();
+
+        // Nothing is stored when the save file did not change.
+        store_backup(&save_file_path).unwrap();
+        write(&save_file_path, "session 1").unwrap();
+        store_backup(&save_file_path).unwrap();
+
+        let retention_policy = RetentionPolicy {
+            keep_last: 1,
+            keep_daily: Some(0),
+            keep_weekly: Some(0),
+        };
+        prune_backups(&retention_policy, get_timestamp().unwrap()).unwrap();
+
+        let history = read_history().unwrap();
+        let operations: Vec<Operation> = history
+            .iter()
+            .map(|history_entry| history_entry.operation)
+            .collect();
+        assert!(operations == [Operation::Backup, Operation::Backup, Operation::Prune]);
+        let hash = get_hash(b"session 0");
+        assert_eq!(history[0].hash_after.as_ref(), Some(&hash));
+        assert_eq!(history[2].hash_before.as_ref(), Some(&hash));
+        assert_eq!(history[2].hash_after, None);
+        assert_eq!(history[2].source_path.as_ref(), Some(&save_file_path));
+
+        // Neither of them changed a save file, so there is nothing to undo.
+        assert!(get_operation_to_undo().is_err());
+    }
+}
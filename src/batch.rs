@@ -0,0 +1,97 @@
+use crate::{
+    backup::backup,
+    delete::delete,
+    enums::{Activity, IsaacVersion},
+    install::{install_save_file, prompt_for_save_file_to_install},
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+
+enum SlotResult {
+    Done,
+    Skipped(String),
+    Failed(String),
+}
+
+/// Runs an install, backup, or delete for each of the selected slots. An error in one slot does not
+/// stop the other slots from being processed.
+pub fn batch(
+    activity: Activity,
+    save_file_slots: &[usize],
+    existing_save_files: &[(Utf8PathBuf, bool)],
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    // The save file to install is only chosen once for all of the slots.
+    let save_file_to_install = match activity {
+        Activity::Install => Some(prompt_for_save_file_to_install(isaac_version)?),
+        _ => None,
+    };
+
+    let mut results = Vec::new();
+    for save_file_slot in save_file_slots {
+        let save_file_index = save_file_slot - 1;
+        let save_file = existing_save_files.get(save_file_index).context(format!(
+            "Failed to get the save file at index: {}",
+            save_file_index
+        ))?;
+        let (save_file_path, exists) = save_file;
+
+        let result = match activity {
+            Activity::Install => {
+                let (save_file_bytes, description) = save_file_to_install
+                    .as_ref()
+                    .context("Failed to get the save file to install.")?;
+                install_save_file(save_file, save_file_bytes, description)
+            }
+            Activity::Backup | Activity::Delete if !exists => {
+                results.push((
+                    *save_file_slot,
+                    save_file_path,
+                    SlotResult::Skipped("The slot is empty.".to_string()),
+                ));
+                continue;
+            }
+            Activity::Backup => backup(save_file, *save_file_slot),
+            Activity::Delete => delete(save_file, *save_file_slot),
+            _ => bail!("You can only install, backup, or delete multiple save files at once."),
+        };
+        println!();
+
+        let slot_result = match result {
+            Ok(()) => SlotResult::Done,
+            Err(err) => SlotResult::Failed(err.to_string().replace('\n', " ")),
+        };
+        results.push((*save_file_slot, save_file_path, slot_result));
+    }
+
+    print_summary(&results);
+
+    let num_failed = results
+        .iter()
+        .filter(|(_, _, slot_result)| matches!(slot_result, SlotResult::Failed(_)))
+        .count();
+    if num_failed > 0 {
+        bail!(
+            "{} out of {} save files could not be processed.",
+            num_failed,
+            results.len(),
+        );
+    }
+
+    Ok(())
+}
+
+fn print_summary(results: &[(usize, &Utf8PathBuf, SlotResult)]) {
+    println!("Summary:");
+    println!("{:<6} {:<9} Details", "Slot", "Result");
+    for (save_file_slot, save_file_path, slot_result) in results {
+        let (result, details) = match slot_result {
+            SlotResult::Done => (format!("{:<9}", "Done").green(), save_file_path.to_string()),
+            SlotResult::Skipped(reason) => (format!("{:<9}", "Skipped").yellow(), reason.clone()),
+            SlotResult::Failed(reason) => (format!("{:<9}", "Failed").red(), reason.clone()),
+        };
+        println!("{:<6} {} {}", save_file_slot, result, details);
+    }
+    println!();
+}
@@ -0,0 +1,203 @@
+use crate::{
+    dry_run::{get_success_prefix, write_file},
+    entity_names::ENTITY_NAMES,
+    enums::{BestiaryStat, BestiaryView, IsaacVersion},
+    errors::{ErrorKind, InstallerError},
+    get_input::prompt_for_bestiary_view,
+    history::get_timestamp,
+    persistent_game_data::{read_persistent_game_data, Bestiary},
+    utils::get_dir_of_running_exe,
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Default, Serialize)]
+struct BestiaryRow {
+    entity_type: u32,
+    entity_variant: u32,
+    name: &'static str,
+    encounters: u32,
+    kills: u32,
+    hits: u32,
+    deaths: u32,
+}
+
+pub fn bestiary(
+    (save_file_path, exists): &(Utf8PathBuf, bool),
+    save_file_slot: usize,
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    if !matches!(
+        isaac_version,
+        IsaacVersion::Repentance | IsaacVersion::RepentancePlus,
+    ) {
+        bail!("The bestiary is only available in Repentance and Repentance+.");
+    }
+
+    if !exists {
+        bail!(InstallerError::new(
+            ErrorKind::SlotEmpty,
+            format!(
+                "You cannot view the bestiary for slot {} since the corresponding file does not exist.",
+                save_file_slot,
+            ),
+        ));
+    }
+
+    let persistent_game_data = read_persistent_game_data(save_file_path, isaac_version)?;
+    let bestiary = persistent_game_data
+        .bestiary
+        .context("The save file does not have a bestiary section.")?;
+    let mut rows = get_bestiary_rows(&bestiary);
+
+    let bestiary_view = prompt_for_bestiary_view()?;
+    let sort_stat = match bestiary_view {
+        BestiaryView::SortByEntity => None,
+        BestiaryView::SortByEncounters => Some(BestiaryStat::Encounters),
+        BestiaryView::SortByKills => Some(BestiaryStat::Kills),
+        BestiaryView::SortByHits => Some(BestiaryStat::Hits),
+        BestiaryView::SortByDeaths => Some(BestiaryStat::Deaths),
+        BestiaryView::ExportJson => return export_bestiary(&rows, save_file_slot),
+    };
+
+    if let Some(stat) = sort_stat {
+        rows.sort_by_key(|row| std::cmp::Reverse(get_stat_value(row, stat)));
+    }
+    print_bestiary(&rows);
+
+    Ok(())
+}
+
+/// Merges the separate lists of the bestiary section into one row per entity, sorted by entity.
+fn get_bestiary_rows(bestiary: &Bestiary) -> Vec<BestiaryRow> {
+    let mut rows: BTreeMap<u32, BestiaryRow> = BTreeMap::new();
+    for list in &bestiary.lists {
+        let Some(stat) = BestiaryStat::from_repr(list.list_type) else {
+            continue;
+        };
+
+        for entry in &list.entries {
+            let row = rows.entry(entry.entity_key).or_insert_with(|| BestiaryRow {
+                entity_type: entry.get_entity_type(),
+                entity_variant: entry.get_entity_variant(),
+                name: get_entity_name(entry.get_entity_type(), entry.get_entity_variant()),
+                ..Default::default()
+            });
+            match stat {
+                BestiaryStat::Encounters => row.encounters = entry.value,
+                BestiaryStat::Kills => row.kills = entry.value,
+                BestiaryStat::Hits => row.hits = entry.value,
+                BestiaryStat::Deaths => row.deaths = entry.value,
+            }
+        }
+    }
+
+    rows.into_values().collect()
+}
+
+/// Variants without a name of their own use the name of variant 0.
+fn get_entity_name(entity_type: u32, entity_variant: u32) -> &'static str {
+    let find = |entity_variant: u32| {
+        ENTITY_NAMES
+            .binary_search_by_key(&(entity_type, entity_variant), |(id, variant, _)| {
+                (*id, *variant)
+            })
+            .map(|index| ENTITY_NAMES[index].2)
+    };
+
+    find(entity_variant)
+        .or_else(|_| find(0))
+        .unwrap_or("Unknown Entity")
+}
+
+fn get_stat_value(row: &BestiaryRow, stat: BestiaryStat) -> u32 {
+    match stat {
+        BestiaryStat::Encounters => row.encounters,
+        BestiaryStat::Kills => row.kills,
+        BestiaryStat::Hits => row.hits,
+        BestiaryStat::Deaths => row.deaths,
+    }
+}
+
+fn print_bestiary(rows: &[BestiaryRow]) {
+    let entities: Vec<String> = rows
+        .iter()
+        .map(|row| format!("{} ({}.{})", row.name, row.entity_type, row.entity_variant))
+        .collect();
+    let width = entities
+        .iter()
+        .map(|entity| entity.len())
+        .max()
+        .unwrap_or_default()
+        .max("Entity".len());
+
+    println!(
+        "{:<width$}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Entity",
+        BestiaryStat::Encounters,
+        BestiaryStat::Kills,
+        BestiaryStat::Hits,
+        BestiaryStat::Deaths,
+    );
+    for (row, entity) in rows.iter().zip(entities) {
+        println!(
+            "{:<width$}  {:>10}  {:>10}  {:>10}  {:>10}",
+            entity, row.encounters, row.kills, row.hits, row.deaths,
+        );
+    }
+    println!();
+}
+
+/// The file name includes a timestamp, so that an earlier export is never overwritten.
+fn export_bestiary(rows: &[BestiaryRow], save_file_slot: usize) -> Result<()> {
+    let dir_path = get_dir_of_running_exe()?;
+    let destination_path = dir_path.join(format!(
+        "bestiary{}.{}.json",
+        save_file_slot,
+        get_timestamp()?,
+    ));
+
+    let json = serde_json::to_string_pretty(rows).context("Failed to serialize the bestiary.")?;
+    write_file(&destination_path, json)?;
+
+    println!(
+        "{} the bestiary for {} entities to:\n{}",
+        get_success_prefix("exported", "export"),
+        rows.len(),
+        destination_path.to_string().green(),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entity_names_are_sorted() {
+        for pair in ENTITY_NAMES.windows(2) {
+            assert!(
+                (pair[0].0, pair[0].1) < (pair[1].0, pair[1].1),
+                "{} is not sorted",
+                pair[1].2,
+            );
+        }
+    }
+
+    #[test]
+    fn names_each_variant() {
+        assert_eq!(get_entity_name(10, 0), "Frowning Gaper");
+        assert_eq!(get_entity_name(10, 2), "Flaming Gaper");
+        assert_eq!(get_entity_name(951, 40), "Ultra Death");
+    }
+
+    #[test]
+    fn falls_back_to_the_base_entity() {
+        assert_eq!(get_entity_name(20, 1), "Monstro");
+        assert_eq!(get_entity_name(1, 0), "Unknown Entity");
+    }
+}
@@ -0,0 +1,49 @@
+/// The names of the challenges, indexed by challenge ID.
+pub const CHALLENGE_NAMES: [&str; 46] = [
+    "",                           // 0
+    "Pitch Black",                // 1
+    "High Brow",                  // 2
+    "Head Trauma",                // 3
+    "Darkness Falls",             // 4
+    "The Tank",                   // 5
+    "Solar System",               // 6
+    "Suicide King",               // 7
+    "Cat Got Your Tongue",        // 8
+    "Demo Man",                   // 9
+    "Cursed!",                    // 10
+    "Glass Cannon",               // 11
+    "When Life Gives You Lemons", // 12
+    "Beans!",                     // 13
+    "It's in the Cards",          // 14
+    "Slow Roll",                  // 15
+    "Computer Savvy",             // 16
+    "Waka Waka",                  // 17
+    "The Host",                   // 18
+    "The Family Man",             // 19
+    "Purist",                     // 20
+    "XXXXXXXXL",                  // 21
+    "SPEED!",                     // 22
+    "Blue Bomber",                // 23
+    "PAY TO PLAY",                // 24
+    "Have a Heart",               // 25
+    "I RULE!",                    // 26
+    "BRAINS!",                    // 27
+    "PRIDE DAY!",                 // 28
+    "Onan's Streak",              // 29
+    "The Guardian",               // 30
+    "Backasswards",               // 31
+    "Aprils Fool",                // 32
+    "Pokey Mans",                 // 33
+    "Ultra Hard",                 // 34
+    "PONG",                       // 35
+    "Scat Man",                   // 36
+    "Bloody Mary",                // 37
+    "Baptism by Fire",            // 38
+    "Isaac's Awakening",          // 39
+    "Seeing Double",              // 40
+    "Pica Run",                   // 41
+    "Hot Potato",                 // 42
+    "Cantripped!",                // 43
+    "Red Redemption",             // 44
+    "DELETE THIS",                // 45
+];
@@ -0,0 +1,164 @@
+use crate::{
+    challenge_names::CHALLENGE_NAMES,
+    dry_run::get_success_prefix,
+    enums::{ChallengeAction, IsaacVersion, Operation},
+    errors::{ErrorKind, InstallerError},
+    get_input::{prompt_for_challenge_action, prompt_for_challenge_names},
+    history::{record_operation, FileState},
+    persistent_game_data::{read_persistent_game_data, write_persistent_game_data},
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+
+/// Only the challenge flags are changed. The achievements that the challenges unlock are left as
+/// they are, since this program does not know which achievement belongs to which challenge.
+pub fn challenges(
+    (save_file_path, exists): &(Utf8PathBuf, bool),
+    save_file_slot: usize,
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    if !exists {
+        bail!(InstallerError::new(
+            ErrorKind::SlotEmpty,
+            format!(
+                "You cannot view the challenges for slot {} since the corresponding file does not exist.",
+                save_file_slot,
+            ),
+        ));
+    }
+
+    let mut persistent_game_data = read_persistent_game_data(save_file_path, isaac_version)?;
+    let challenge_ids = get_challenge_ids(persistent_game_data.challenges.len());
+    print_challenges(&persistent_game_data.challenges, &challenge_ids);
+
+    let challenge_action = prompt_for_challenge_action()?;
+    let (ids_to_change, completed) = match challenge_action {
+        ChallengeAction::Nothing => return Ok(()),
+        ChallengeAction::CompleteAll => (challenge_ids, true),
+        ChallengeAction::ResetAll => (challenge_ids, false),
+        ChallengeAction::Complete => (prompt_for_challenge_ids(&challenge_ids)?, true),
+        ChallengeAction::Reset => (prompt_for_challenge_ids(&challenge_ids)?, false),
+    };
+
+    for id in &ids_to_change {
+        persistent_game_data.challenges[*id] = u8::from(completed);
+    }
+    let state_before = FileState::read(save_file_path)?;
+    write_persistent_game_data(save_file_path, &persistent_game_data)?;
+    record_operation(Operation::Edit, None, save_file_path, state_before)?;
+
+    let verb = match completed {
+        true => "completed",
+        false => "reset",
+    };
+    println!(
+        "{} {} challenge(s) as {} in:\n{}",
+        get_success_prefix("marked", "mark"),
+        ids_to_change.len(),
+        verb,
+        save_file_path.to_string().green(),
+    );
+
+    Ok(())
+}
+
+/// Returns the IDs of the challenges that exist in a save file with the given section length.
+pub fn get_challenge_ids(num_challenges: usize) -> Vec<usize> {
+    (1..num_challenges).collect()
+}
+
+pub fn get_challenge_name(id: usize) -> &'static str {
+    CHALLENGE_NAMES
+        .get(id)
+        .copied()
+        .unwrap_or("Unknown Challenge")
+}
+
+fn print_challenges(challenges: &[u8], challenge_ids: &[usize]) {
+    println!("The challenges for this save file are as follows:");
+    for id in challenge_ids {
+        let status = match challenges[*id] {
+            0 => "[not done]".cyan(),
+            _ => "[done]".green(),
+        };
+        println!("{}) {} {}", id, get_challenge_name(*id), status);
+    }
+    println!();
+}
+
+fn prompt_for_challenge_ids(challenge_ids: &[usize]) -> Result<Vec<usize>> {
+    let names = prompt_for_challenge_names()?;
+    if names.is_empty() {
+        bail!("You did not specify any challenges.");
+    }
+
+    names
+        .iter()
+        .map(|name| {
+            find_challenge_id(name, challenge_ids).context(format!(
+                "Failed to find a challenge named: {}",
+                name.green()
+            ))
+        })
+        .collect()
+}
+
+/// Matches either a challenge number or a case-insensitive challenge name.
+fn find_challenge_id(name: &str, challenge_ids: &[usize]) -> Option<usize> {
+    if let Ok(id) = name.parse::<usize>() {
+        return challenge_ids.contains(&id).then_some(id);
+    }
+
+    challenge_ids
+        .iter()
+        .copied()
+        .find(|id| get_challenge_name(*id).eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{persistent_game_data::parse_persistent_game_data, test_utils::FakeTree};
+
+    #[test]
+    fn finds_challenges_by_name_or_id() {
+        let challenge_ids = get_challenge_ids(CHALLENGE_NAMES.len());
+
+        assert_eq!(find_challenge_id("pitch black", &challenge_ids), Some(1));
+        assert_eq!(find_challenge_id("3", &challenge_ids), Some(3));
+        assert_eq!(find_challenge_id("0", &challenge_ids), None);
+        assert_eq!(find_challenge_id("Not A Challenge", &challenge_ids), None);
+    }
+
+    #[test]
+    fn completes_and_resets_challenges() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+
+        // Reset slot 1, and then complete two challenges.
+        fake_tree
+            .run(&["y", "5", "13", "1", "8", "1", "4", "Pitch Black, 2", "19"])
+            .unwrap();
+        let persistent_game_data = parse_persistent_game_data(&save_file_path).unwrap();
+        let challenges = &persistent_game_data.challenges;
+        assert_eq!(challenges[1], 1);
+        assert_eq!(challenges[2], 1);
+        assert_eq!(challenges[3], 0);
+        let achievements_before = persistent_game_data.achievements.clone();
+
+        // Complete everything, and then reset one challenge. The file is parsed again each time, so
+        // its checksum is still valid.
+        fake_tree
+            .run(&["y", "5", "8", "1", "2", "8", "1", "5", "2", "19"])
+            .unwrap();
+        let persistent_game_data = parse_persistent_game_data(&save_file_path).unwrap();
+        let challenges = &persistent_game_data.challenges;
+        assert_eq!(challenges[2], 0);
+        assert!(get_challenge_ids(challenges.len())
+            .iter()
+            .filter(|id| **id != 2)
+            .all(|id| challenges[*id] == 1));
+        assert_eq!(persistent_game_data.achievements, achievements_before);
+    }
+}
@@ -0,0 +1,737 @@
+/// The names of the collectibles, indexed by collectible ID. IDs that do not correspond to an item on
+/// the collection page are empty strings.
+pub const COLLECTIBLE_NAMES: [&str; 733] = [
+    "",                        // 0
+    "The Sad Onion",           // 1
+    "The Inner Eye",           // 2
+    "Spoon Bender",            // 3
+    "Cricket's Head",          // 4
+    "My Reflection",           // 5
+    "Number One",              // 6
+    "Blood of the Martyr",     // 7
+    "Brother Bobby",           // 8
+    "Skatole",                 // 9
+    "Halo of Flies",           // 10
+    "1up!",                    // 11
+    "Magic Mushroom",          // 12
+    "The Virus",               // 13
+    "Roid Rage",               // 14
+    "<3",                      // 15
+    "Raw Liver",               // 16
+    "Skeleton Key",            // 17
+    "A Dollar",                // 18
+    "Boom!",                   // 19
+    "Transcendence",           // 20
+    "The Compass",             // 21
+    "Lunch",                   // 22
+    "Dinner",                  // 23
+    "Dessert",                 // 24
+    "Breakfast",               // 25
+    "Rotten Meat",             // 26
+    "Wooden Spoon",            // 27
+    "The Belt",                // 28
+    "Mom's Underwear",         // 29
+    "Mom's Heels",             // 30
+    "Mom's Lipstick",          // 31
+    "Wire Coat Hanger",        // 32
+    "The Bible",               // 33
+    "The Book of Belial",      // 34
+    "The Necronomicon",        // 35
+    "The Poop",                // 36
+    "Mr. Boom",                // 37
+    "Tammy's Head",            // 38
+    "Mom's Bra",               // 39
+    "Kamikaze!",               // 40
+    "Mom's Pad",               // 41
+    "Bob's Rotten Head",       // 42
+    "",                        // 43
+    "Teleport!",               // 44
+    "Yum Heart",               // 45
+    "Lucky Foot",              // 46
+    "Doctor's Remote",         // 47
+    "Cupid's Arrow",           // 48
+    "Shoop da Whoop!",         // 49
+    "Steven",                  // 50
+    "Pentagram",               // 51
+    "Dr. Fetus",               // 52
+    "Magneto",                 // 53
+    "Treasure Map",            // 54
+    "Mom's Eye",               // 55
+    "Lemon Mishap",            // 56
+    "Distant Admiration",      // 57
+    "Book of Shadows",         // 58
+    "",                        // 59
+    "The Ladder",              // 60
+    "",                        // 61
+    "Charm of the Vampire",    // 62
+    "The Battery",             // 63
+    "Steam Sale",              // 64
+    "Anarchist Cookbook",      // 65
+    "The Hourglass",           // 66
+    "Sister Maggy",            // 67
+    "Technology",              // 68
+    "Chocolate Milk",          // 69
+    "Growth Hormones",         // 70
+    "Mini Mush",               // 71
+    "Rosary",                  // 72
+    "Cube of Meat",            // 73
+    "A Quarter",               // 74
+    "PHD",                     // 75
+    "X-Ray Vision",            // 76
+    "My Little Unicorn",       // 77
+    "Book of Revelations",     // 78
+    "The Mark",                // 79
+    "The Pact",                // 80
+    "Dead Cat",                // 81
+    "Lord of the Pit",         // 82
+    "The Nail",                // 83
+    "We Need to Go Deeper!",   // 84
+    "Deck of Cards",           // 85
+    "Monstro's Tooth",         // 86
+    "Loki's Horns",            // 87
+    "Little Chubby",           // 88
+    "Spider Bite",             // 89
+    "The Small Rock",          // 90
+    "Spelunker Hat",           // 91
+    "Super Bandage",           // 92
+    "The Gamekid",             // 93
+    "Sack of Pennies",         // 94
+    "Robo-Baby",               // 95
+    "Little C.H.A.D.",         // 96
+    "The Book of Sin",         // 97
+    "The Relic",               // 98
+    "Little Gish",             // 99
+    "Little Steven",           // 100
+    "The Halo",                // 101
+    "Mom's Bottle of Pills",   // 102
+    "The Common Cold",         // 103
+    "The Parasite",            // 104
+    "The D6",                  // 105
+    "Mr. Mega",                // 106
+    "The Pinking Shears",      // 107
+    "The Wafer",               // 108
+    "Money = Power",           // 109
+    "Mom's Contacts",          // 110
+    "The Bean",                // 111
+    "Guardian Angel",          // 112
+    "Demon Baby",              // 113
+    "Mom's Knife",             // 114
+    "Ouija Board",             // 115
+    "9 Volt",                  // 116
+    "Dead Bird",               // 117
+    "Brimstone",               // 118
+    "Blood Bag",               // 119
+    "Odd Mushroom (Thin)",     // 120
+    "Odd Mushroom (Large)",    // 121
+    "Whore of Babylon",        // 122
+    "Monster Manual",          // 123
+    "Dead Sea Scrolls",        // 124
+    "Bobby-Bomb",              // 125
+    "Razor Blade",             // 126
+    "Forget Me Now",           // 127
+    "Forever Alone",           // 128
+    "Bucket of Lard",          // 129
+    "A Pony",                  // 130
+    "Bomb Bag",                // 131
+    "A Lump of Coal",          // 132
+    "Guppy's Paw",             // 133
+    "Guppy's Tail",            // 134
+    "IV Bag",                  // 135
+    "Best Friend",             // 136
+    "Remote Detonator",        // 137
+    "Stigmata",                // 138
+    "Mom's Purse",             // 139
+    "Bob's Curse",             // 140
+    "Pageant Boy",             // 141
+    "Scapular",                // 142
+    "Speed Ball",              // 143
+    "Bum Friend",              // 144
+    "Guppy's Head",            // 145
+    "Prayer Card",             // 146
+    "Notched Axe",             // 147
+    "Infestation",             // 148
+    "Ipecac",                  // 149
+    "Tough Love",              // 150
+    "The Mulligan",            // 151
+    "Technology 2",            // 152
+    "Mutant Spider",           // 153
+    "Chemical Peel",           // 154
+    "The Peeper",              // 155
+    "Habit",                   // 156
+    "Bloody Lust",             // 157
+    "Crystal Ball",            // 158
+    "Spirit of the Night",     // 159
+    "Crack the Sky",           // 160
+    "Ankh",                    // 161
+    "Celtic Cross",            // 162
+    "Ghost Baby",              // 163
+    "The Candle",              // 164
+    "Cat-o-nine-tails",        // 165
+    "D20",                     // 166
+    "Harlequin Baby",          // 167
+    "Epic Fetus",              // 168
+    "Polyphemus",              // 169
+    "Daddy Longlegs",          // 170
+    "Spider Butt",             // 171
+    "Sacrificial Dagger",      // 172
+    "Mitre",                   // 173
+    "Rainbow Baby",            // 174
+    "Dad's Key",               // 175
+    "Stem Cells",              // 176
+    "Portable Slot",           // 177
+    "Holy Water",              // 178
+    "Fate",                    // 179
+    "The Black Bean",          // 180
+    "White Pony",              // 181
+    "Sacred Heart",            // 182
+    "Tooth Picks",             // 183
+    "Holy Grail",              // 184
+    "Dead Dove",               // 185
+    "Blood Rights",            // 186
+    "Guppy's Hairball",        // 187
+    "Abel",                    // 188
+    "SMB Super Fan",           // 189
+    "Pyro",                    // 190
+    "3 Dollar Bill",           // 191
+    "Telepathy for Dummies",   // 192
+    "MEAT!",                   // 193
+    "Magic 8 Ball",            // 194
+    "Mom's Coin Purse",        // 195
+    "Squeezy",                 // 196
+    "Jesus Juice",             // 197
+    "Box",                     // 198
+    "Mom's Key",               // 199
+    "Mom's Eyeshadow",         // 200
+    "Iron Bar",                // 201
+    "Midas' Touch",            // 202
+    "Humbleing Bundle",        // 203
+    "Fanny Pack",              // 204
+    "Sharp Plug",              // 205
+    "Guillotine",              // 206
+    "Ball of Bandages",        // 207
+    "Champion Belt",           // 208
+    "Butt Bombs",              // 209
+    "Gnawed Leaf",             // 210
+    "Spiderbaby",              // 211
+    "Guppy's Collar",          // 212
+    "Lost Contact",            // 213
+    "Anemic",                  // 214
+    "Goat Head",               // 215
+    "Ceremonial Robes",        // 216
+    "Mom's Wig",               // 217
+    "Placenta",                // 218
+    "Old Bandage",             // 219
+    "Sad Bombs",               // 220
+    "Rubber Cement",           // 221
+    "Anti-Gravity",            // 222
+    "Pyromaniac",              // 223
+    "Cricket's Body",          // 224
+    "Gimpy",                   // 225
+    "Black Lotus",             // 226
+    "Piggy Bank",              // 227
+    "Mom's Perfume",           // 228
+    "Monstro's Lung",          // 229
+    "Abaddon",                 // 230
+    "Ball of Tar",             // 231
+    "Stop Watch",              // 232
+    "Tiny Planet",             // 233
+    "Infestation 2",           // 234
+    "",                        // 235
+    "E. Coli",                 // 236
+    "Death's Touch",           // 237
+    "Key Piece 1",             // 238
+    "Key Piece 2",             // 239
+    "Experimental Treatment",  // 240
+    "Contract from Below",     // 241
+    "Infamy",                  // 242
+    "Trinity Shield",          // 243
+    "Tech.5",                  // 244
+    "20/20",                   // 245
+    "Blue Map",                // 246
+    "BFFS!",                   // 247
+    "Hive Mind",               // 248
+    "There's Options",         // 249
+    "BOGO Bombs",              // 250
+    "Starter Deck",            // 251
+    "Little Baggy",            // 252
+    "Magic Scab",              // 253
+    "Blood Clot",              // 254
+    "Screw",                   // 255
+    "Hot Bombs",               // 256
+    "Fire Mind",               // 257
+    "Missing No.",             // 258
+    "Dark Matter",             // 259
+    "Black Candle",            // 260
+    "Proptosis",               // 261
+    "Missing Page 2",          // 262
+    "Clear Rune",              // 263
+    "Smart Fly",               // 264
+    "Dry Baby",                // 265
+    "Juicy Sack",              // 266
+    "Robo-Baby 2.0",           // 267
+    "Rotten Baby",             // 268
+    "Headless Baby",           // 269
+    "Leech",                   // 270
+    "Mystery Sack",            // 271
+    "BBF",                     // 272
+    "Bob's Brain",             // 273
+    "Best Bud",                // 274
+    "Lil Brimstone",           // 275
+    "Isaac's Heart",           // 276
+    "Lil Haunt",               // 277
+    "Dark Bum",                // 278
+    "Big Fan",                 // 279
+    "Sissy Longlegs",          // 280
+    "Punching Bag",            // 281
+    "How to Jump",             // 282
+    "D100",                    // 283
+    "D4",                      // 284
+    "D10",                     // 285
+    "Blank Card",              // 286
+    "Book of Secrets",         // 287
+    "Box of Spiders",          // 288
+    "Red Candle",              // 289
+    "The Jar",                 // 290
+    "Flush!",                  // 291
+    "Satanic Bible",           // 292
+    "Head of Krampus",         // 293
+    "Butter Bean",             // 294
+    "Magic Fingers",           // 295
+    "Converter",               // 296
+    "Pandora's Box",           // 297
+    "Unicorn Stump",           // 298
+    "Taurus",                  // 299
+    "Aries",                   // 300
+    "Cancer",                  // 301
+    "Leo",                     // 302
+    "Virgo",                   // 303
+    "Libra",                   // 304
+    "Scorpio",                 // 305
+    "Sagittarius",             // 306
+    "Capricorn",               // 307
+    "Aquarius",                // 308
+    "Pisces",                  // 309
+    "Eve's Mascara",           // 310
+    "Judas' Shadow",           // 311
+    "Maggy's Bow",             // 312
+    "Holy Mantle",             // 313
+    "Thunder Thighs",          // 314
+    "Strange Attractor",       // 315
+    "Cursed Eye",              // 316
+    "Mysterious Liquid",       // 317
+    "Gemini",                  // 318
+    "Cain's Other Eye",        // 319
+    "???'s Only Friend",       // 320
+    "Samson's Chains",         // 321
+    "Mongo Baby",              // 322
+    "Isaac's Tears",           // 323
+    "Undefined",               // 324
+    "Scissors",                // 325
+    "Breath of Life",          // 326
+    "The Polaroid",            // 327
+    "The Negative",            // 328
+    "The Ludovico Technique",  // 329
+    "Soy Milk",                // 330
+    "Godhead",                 // 331
+    "Lazarus' Rags",           // 332
+    "The Mind",                // 333
+    "The Body",                // 334
+    "The Soul",                // 335
+    "Dead Onion",              // 336
+    "Broken Watch",            // 337
+    "The Boomerang",           // 338
+    "Safety Pin",              // 339
+    "Caffeine Pill",           // 340
+    "Torn Photo",              // 341
+    "Blue Cap",                // 342
+    "Latch Key",               // 343
+    "Match Book",              // 344
+    "Synthoil",                // 345
+    "A Snack",                 // 346
+    "Diplopia",                // 347
+    "Placebo",                 // 348
+    "Wooden Nickel",           // 349
+    "Toxic Shock",             // 350
+    "Mega Bean",               // 351
+    "Glass Cannon",            // 352
+    "Bomber Boy",              // 353
+    "Crack Jacks",             // 354
+    "Mom's Pearls",            // 355
+    "Car Battery",             // 356
+    "Box of Friends",          // 357
+    "The Wiz",                 // 358
+    "8 Inch Nails",            // 359
+    "Incubus",                 // 360
+    "Fate's Reward",           // 361
+    "Lil Chest",               // 362
+    "Sworn Protector",         // 363
+    "Friend Zone",             // 364
+    "Lost Fly",                // 365
+    "Scatter Bombs",           // 366
+    "Sticky Bombs",            // 367
+    "Epiphora",                // 368
+    "Continuum",               // 369
+    "Mr. Dolly",               // 370
+    "Curse of the Tower",      // 371
+    "Charged Baby",            // 372
+    "Dead Eye",                // 373
+    "Holy Light",              // 374
+    "Host Hat",                // 375
+    "Restock",                 // 376
+    "Bursting Sack",           // 377
+    "No. 2",                   // 378
+    "Pupula Duplex",           // 379
+    "Pay to Play",             // 380
+    "Eden's Blessing",         // 381
+    "Friendly Ball",           // 382
+    "Tear Detonator",          // 383
+    "Lil Gurdy",               // 384
+    "Bumbo",                   // 385
+    "D12",                     // 386
+    "Censer",                  // 387
+    "Key Bum",                 // 388
+    "Rune Bag",                // 389
+    "Seraphim",                // 390
+    "Betrayal",                // 391
+    "Zodiac",                  // 392
+    "Serpent's Kiss",          // 393
+    "Marked",                  // 394
+    "Tech X",                  // 395
+    "Ventricle Razor",         // 396
+    "Tractor Beam",            // 397
+    "God's Flesh",             // 398
+    "Maw of the Void",         // 399
+    "Spear of Destiny",        // 400
+    "Explosivo",               // 401
+    "Chaos",                   // 402
+    "Spider Mod",              // 403
+    "Farting Baby",            // 404
+    "GB Bug",                  // 405
+    "D8",                      // 406
+    "Purity",                  // 407
+    "Athame",                  // 408
+    "Empty Vessel",            // 409
+    "Evil Eye",                // 410
+    "Lusty Blood",             // 411
+    "Cambion Conception",      // 412
+    "Immaculate Conception",   // 413
+    "More Options",            // 414
+    "Crown of Light",          // 415
+    "Deep Pockets",            // 416
+    "Succubus",                // 417
+    "Fruit Cake",              // 418
+    "Teleport 2.0",            // 419
+    "Black Powder",            // 420
+    "Kidney Bean",             // 421
+    "Glowing Hourglass",       // 422
+    "Circle of Protection",    // 423
+    "Sack Head",               // 424
+    "Night Light",             // 425
+    "Obsessed Fan",            // 426
+    "Mine Crafter",            // 427
+    "PJs",                     // 428
+    "Head of the Keeper",      // 429
+    "Papa Fly",                // 430
+    "Multidimensional Baby",   // 431
+    "Glitter Bombs",           // 432
+    "My Shadow",               // 433
+    "Jar of Flies",            // 434
+    "Lil Loki",                // 435
+    "Milk!",                   // 436
+    "D7",                      // 437
+    "Binky",                   // 438
+    "Mom's Box",               // 439
+    "Kidney Stone",            // 440
+    "Mega Blast",              // 441
+    "Dark Prince's Crown",     // 442
+    "Apple!",                  // 443
+    "Lead Pencil",             // 444
+    "Dog Tooth",               // 445
+    "Dead Tooth",              // 446
+    "Linger Bean",             // 447
+    "Shard of Glass",          // 448
+    "Metal Plate",             // 449
+    "Eye of Greed",            // 450
+    "Tarot Cloth",             // 451
+    "Varicose Veins",          // 452
+    "Compound Fracture",       // 453
+    "Polydactyly",             // 454
+    "Dad's Lost Coin",         // 455
+    "Midnight Snack",          // 456
+    "Cone Head",               // 457
+    "Belly Button",            // 458
+    "Sinus Infection",         // 459
+    "Glaucoma",                // 460
+    "Parasitoid",              // 461
+    "Eye of Belial",           // 462
+    "Sulfuric Acid",           // 463
+    "Glyph of Balance",        // 464
+    "Analog Stick",            // 465
+    "Contagion",               // 466
+    "Finger!",                 // 467
+    "Shade",                   // 468
+    "Depression",              // 469
+    "Hushy",                   // 470
+    "Lil Monstro",             // 471
+    "King Baby",               // 472
+    "Big Chubby",              // 473
+    "Broken Glass Cannon",     // 474
+    "Plan C",                  // 475
+    "D1",                      // 476
+    "Void",                    // 477
+    "Pause",                   // 478
+    "Smelter",                 // 479
+    "Compost",                 // 480
+    "Dataminer",               // 481
+    "Clicker",                 // 482
+    "Mama Mega!",              // 483
+    "Wait What?",              // 484
+    "Crooked Penny",           // 485
+    "Dull Razor",              // 486
+    "Potato Peeler",           // 487
+    "Metronome",               // 488
+    "D Infinity",              // 489
+    "Eden's Soul",             // 490
+    "Acid Baby",               // 491
+    "YO LISTEN!",              // 492
+    "Adrenaline",              // 493
+    "Jacob's Ladder",          // 494
+    "Ghost Pepper",            // 495
+    "Euthanasia",              // 496
+    "Camo Undies",             // 497
+    "Duality",                 // 498
+    "Eucharist",               // 499
+    "Sack of Sacks",           // 500
+    "Greed's Gullet",          // 501
+    "Large Zit",               // 502
+    "Little Horn",             // 503
+    "Brown Nugget",            // 504
+    "Poke Go",                 // 505
+    "Backstabber",             // 506
+    "Sharp Straw",             // 507
+    "Mom's Razor",             // 508
+    "Bloodshot Eye",           // 509
+    "Delirious",               // 510
+    "Angry Fly",               // 511
+    "Black Hole",              // 512
+    "Bozo",                    // 513
+    "Broken Modem",            // 514
+    "Mystery Gift",            // 515
+    "Sprinkler",               // 516
+    "Fast Bombs",              // 517
+    "Buddy in a Box",          // 518
+    "Lil Delirium",            // 519
+    "Jumper Cables",           // 520
+    "Coupon",                  // 521
+    "Telekinesis",             // 522
+    "Moving Box",              // 523
+    "Technology Zero",         // 524
+    "Leprosy",                 // 525
+    "7 Seals",                 // 526
+    "Mr. ME!",                 // 527
+    "Angelic Prism",           // 528
+    "Pop!",                    // 529
+    "Death's List",            // 530
+    "Haemolacria",             // 531
+    "Lachryphagy",             // 532
+    "Trisagion",               // 533
+    "Schoolbag",               // 534
+    "Blanket",                 // 535
+    "Sacrificial Altar",       // 536
+    "Lil Spewer",              // 537
+    "Marbles",                 // 538
+    "Mystery Egg",             // 539
+    "Flat Stone",              // 540
+    "Marrow",                  // 541
+    "Slipped Rib",             // 542
+    "Hallowed Ground",         // 543
+    "Pointy Rib",              // 544
+    "Book of the Dead",        // 545
+    "Dad's Ring",              // 546
+    "Divorce Papers",          // 547
+    "Jaw Bone",                // 548
+    "Brittle Bones",           // 549
+    "Broken Shovel",           // 550
+    "Broken Shovel (Passive)", // 551
+    "Mom's Shovel",            // 552
+    "Mucormycosis",            // 553
+    "2Spooky",                 // 554
+    "Golden Razor",            // 555
+    "Sulfur",                  // 556
+    "Fortune Cookie",          // 557
+    "Eye Sore",                // 558
+    "120 Volt",                // 559
+    "It Hurts",                // 560
+    "Almond Milk",             // 561
+    "Rock Bottom",             // 562
+    "Nancy Bombs",             // 563
+    "A Bar of Soap",           // 564
+    "Blood Puppy",             // 565
+    "Dream Catcher",           // 566
+    "Paschal Candle",          // 567
+    "Divine Intervention",     // 568
+    "Blood Oath",              // 569
+    "Playdough Cookie",        // 570
+    "Orphan Socks",            // 571
+    "Eye of the Occult",       // 572
+    "Immaculate Heart",        // 573
+    "Monstrance",              // 574
+    "The Intruder",            // 575
+    "Dirty Mind",              // 576
+    "Damocles",                // 577
+    "Free Lemonade",           // 578
+    "Spirit Sword",            // 579
+    "Red Key",                 // 580
+    "Psy Fly",                 // 581
+    "Wavy Cap",                // 582
+    "Rocket in a Jar",         // 583
+    "Book of Virtues",         // 584
+    "Alabaster Box",           // 585
+    "The Stairway",            // 586
+    "",                        // 587
+    "Sol",                     // 588
+    "Luna",                    // 589
+    "Mercurius",               // 590
+    "Venus",                   // 591
+    "Terra",                   // 592
+    "Mars",                    // 593
+    "Jupiter",                 // 594
+    "Saturnus",                // 595
+    "Uranus",                  // 596
+    "Neptunus",                // 597
+    "Pluto",                   // 598
+    "Voodoo Head",             // 599
+    "Eye Drops",               // 600
+    "Act of Contrition",       // 601
+    "Member Card",             // 602
+    "Battery Pack",            // 603
+    "Mom's Bracelet",          // 604
+    "The Scooper",             // 605
+    "Ocular Rift",             // 606
+    "Boiled Baby",             // 607
+    "Freezer Baby",            // 608
+    "Eternal D6",              // 609
+    "Bird Cage",               // 610
+    "Larynx",                  // 611
+    "Lost Soul",               // 612
+    "",                        // 613
+    "Blood Bombs",             // 614
+    "Lil Dumpy",               // 615
+    "Bird's Eye",              // 616
+    "Lodestone",               // 617
+    "Rotten Tomato",           // 618
+    "Birthright",              // 619
+    "",                        // 620
+    "Red Stew",                // 621
+    "Genesis",                 // 622
+    "Sharp Key",               // 623
+    "Booster Pack",            // 624
+    "Mega Mush",               // 625
+    "Knife Piece 1",           // 626
+    "Knife Piece 2",           // 627
+    "Death Certificate",       // 628
+    "Bot Fly",                 // 629
+    "",                        // 630
+    "Meat Cleaver",            // 631
+    "Evil Charm",              // 632
+    "Dogma",                   // 633
+    "Purgatory",               // 634
+    "Stitches",                // 635
+    "R Key",                   // 636
+    "Knockout Drops",          // 637
+    "Eraser",                  // 638
+    "Yuck Heart",              // 639
+    "Urn of Souls",            // 640
+    "Akeldama",                // 641
+    "Magic Skin",              // 642
+    "Revelation",              // 643
+    "Consolation Prize",       // 644
+    "Tinytoma",                // 645
+    "Brimstone Bombs",         // 646
+    "4.5 Volt",                // 647
+    "",                        // 648
+    "Fruity Plum",             // 649
+    "Plum Flute",              // 650
+    "Star of Bethlehem",       // 651
+    "Cube Baby",               // 652
+    "Vade Retro",              // 653
+    "False PHD",               // 654
+    "Spin to Win",             // 655
+    "Damocles (Passive)",      // 656
+    "Vasculitis",              // 657
+    "Giant Cell",              // 658
+    "Tropicamide",             // 659
+    "Card Reading",            // 660
+    "Quints",                  // 661
+    "",                        // 662
+    "Tooth and Nail",          // 663
+    "Binge Eater",             // 664
+    "Guppy's Eye",             // 665
+    "",                        // 666
+    "Strawman",                // 667
+    "Dad's Note",              // 668
+    "Sausage",                 // 669
+    "Options?",                // 670
+    "Candy Heart",             // 671
+    "A Pound of Flesh",        // 672
+    "Redemption",              // 673
+    "Spirit Shackles",         // 674
+    "Cracked Orb",             // 675
+    "Empty Heart",             // 676
+    "Astral Projection",       // 677
+    "C Section",               // 678
+    "Lil Abaddon",             // 679
+    "Montezuma's Revenge",     // 680
+    "Lil Portal",              // 681
+    "Worm Friend",             // 682
+    "Bone Spurs",              // 683
+    "Hungry Soul",             // 684
+    "Jar of Wisps",            // 685
+    "Soul Locket",             // 686
+    "Friend Finder",           // 687
+    "Inner Child",             // 688
+    "Glitched Crown",          // 689
+    "Jelly Belly",             // 690
+    "Sacred Orb",              // 691
+    "Sanguine Bond",           // 692
+    "The Swarm",               // 693
+    "Heartbreak",              // 694
+    "Bloody Gust",             // 695
+    "Salvation",               // 696
+    "Vanishing Twin",          // 697
+    "Twisted Pair",            // 698
+    "Azazel's Rage",           // 699
+    "Echo Chamber",            // 700
+    "Isaac's Tomb",            // 701
+    "Vengeful Spirit",         // 702
+    "Esau Jr.",                // 703
+    "Berserk!",                // 704
+    "Dark Arts",               // 705
+    "Abyss",                   // 706
+    "Supper",                  // 707
+    "Stapler",                 // 708
+    "Suplex!",                 // 709
+    "Bag of Crafting",         // 710
+    "Flip",                    // 711
+    "Lemegeton",               // 712
+    "Sumptorium",              // 713
+    "Recall",                  // 714
+    "Hold",                    // 715
+    "Keeper's Sack",           // 716
+    "Keeper's Kin",            // 717
+    "",                        // 718
+    "Keeper's Box",            // 719
+    "Everything Jar",          // 720
+    "TMTRAINER",               // 721
+    "Anima Sola",              // 722
+    "Spindown Dice",           // 723
+    "Hypercoagulation",        // 724
+    "IBS",                     // 725
+    "Hemoptysis",              // 726
+    "Ghost Bombs",             // 727
+    "Gello",                   // 728
+    "Decap Attack",            // 729
+    "Glass Eye",               // 730
+    "Stye",                    // 731
+    "Mom's Ring",              // 732
+];
@@ -0,0 +1,264 @@
+use crate::{
+    collectible_names::COLLECTIBLE_NAMES,
+    dry_run::get_success_prefix,
+    enums::{CollectionAction, IsaacVersion, Operation},
+    errors::{ErrorKind, InstallerError},
+    get_input::{prompt_for_collectible_names, prompt_for_collection_action},
+    history::{record_operation, FileState},
+    persistent_game_data::{read_persistent_game_data, write_persistent_game_data},
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+
+/// Clear Rune took over the ID of a removed item in Repentance.
+const CLEAR_RUNE_ID: usize = 263;
+
+pub fn collection(
+    (save_file_path, exists): &(Utf8PathBuf, bool),
+    save_file_slot: usize,
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    if !exists {
+        bail!(InstallerError::new(
+            ErrorKind::SlotEmpty,
+            format!(
+                "You cannot view the collection page for slot {} since the corresponding file does not exist.",
+                save_file_slot,
+            ),
+        ));
+    }
+
+    let mut persistent_game_data = read_persistent_game_data(save_file_path, isaac_version)?;
+    let collectible_ids =
+        get_collectible_ids(persistent_game_data.collectibles.len(), isaac_version);
+    print_collection(
+        &persistent_game_data.collectibles,
+        &collectible_ids,
+        isaac_version,
+    );
+
+    let collection_action = prompt_for_collection_action()?;
+    let (ids_to_change, collected) = match collection_action {
+        CollectionAction::Nothing => return Ok(()),
+        CollectionAction::CollectAll => (collectible_ids, true),
+        CollectionAction::UncollectAll => (collectible_ids, false),
+        CollectionAction::Collect => (
+            prompt_for_collectible_ids(&collectible_ids, isaac_version)?,
+            true,
+        ),
+        CollectionAction::Uncollect => (
+            prompt_for_collectible_ids(&collectible_ids, isaac_version)?,
+            false,
+        ),
+    };
+
+    for id in &ids_to_change {
+        persistent_game_data.collectibles[*id] = u8::from(collected);
+    }
+    let state_before = FileState::read(save_file_path)?;
+    write_persistent_game_data(save_file_path, &persistent_game_data)?;
+    record_operation(Operation::Edit, None, save_file_path, state_before)?;
+
+    let verb = match collected {
+        true => "collected",
+        false => "uncollected",
+    };
+    println!(
+        "{} {} item(s) as {} in:\n{}",
+        get_success_prefix("marked", "mark"),
+        ids_to_change.len(),
+        verb,
+        save_file_path.to_string().green(),
+    );
+
+    Ok(())
+}
+
+/// Returns the IDs of the items that appear on the collection page for the given version.
+pub fn get_collectible_ids(num_collectibles: usize, isaac_version: IsaacVersion) -> Vec<usize> {
+    (1..num_collectibles)
+        .filter(|id| !get_collectible_name(*id, isaac_version).is_empty())
+        .collect()
+}
+
+/// Each version adds its items after the ones of the version before it, so the names only differ in
+/// which IDs exist. Returns an empty string for an ID that is not on the collection page of the given
+/// version.
+pub fn get_collectible_name(id: usize, isaac_version: IsaacVersion) -> &'static str {
+    let is_repentance = matches!(
+        isaac_version,
+        IsaacVersion::Repentance | IsaacVersion::RepentancePlus,
+    );
+    if id > get_last_collectible_id(isaac_version) || (id == CLEAR_RUNE_ID && !is_repentance) {
+        return "";
+    }
+
+    COLLECTIBLE_NAMES.get(id).copied().unwrap_or_default()
+}
+
+/// The Afterbirth+ save files before Booster Pack 5 can be from an earlier booster pack, in which
+/// case the collection page section of the save file is shorter.
+fn get_last_collectible_id(isaac_version: IsaacVersion) -> usize {
+    match isaac_version {
+        IsaacVersion::Rebirth => 346,           // A Snack
+        IsaacVersion::Afterbirth => 441,        // Mega Blast
+        IsaacVersion::AfterbirthPlus => 548,    // Jaw Bone
+        IsaacVersion::AfterbirthPlusBP5 => 552, // Mom's Shovel
+        IsaacVersion::Repentance => 729,        // Decap Attack
+        IsaacVersion::RepentancePlus => 732,    // Mom's Ring
+    }
+}
+
+fn print_collection(collectibles: &[u8], collectible_ids: &[usize], isaac_version: IsaacVersion) {
+    let missing_ids: Vec<usize> = collectible_ids
+        .iter()
+        .copied()
+        .filter(|id| collectibles[*id] == 0)
+        .collect();
+
+    println!(
+        "You have collected {} out of {} items.",
+        collectible_ids.len() - missing_ids.len(),
+        collectible_ids.len(),
+    );
+    if !missing_ids.is_empty() {
+        println!("The following items are missing from the collection page:");
+        for id in missing_ids {
+            println!("{}) {}", id, get_collectible_name(id, isaac_version).cyan());
+        }
+    }
+    println!();
+}
+
+fn prompt_for_collectible_ids(
+    collectible_ids: &[usize],
+    isaac_version: IsaacVersion,
+) -> Result<Vec<usize>> {
+    let names = prompt_for_collectible_names()?;
+    if names.is_empty() {
+        bail!("You did not specify any items.");
+    }
+
+    names
+        .iter()
+        .map(|name| {
+            find_collectible_id(name, collectible_ids, isaac_version)
+                .context(format!("Failed to find an item named: {}", name.green()))
+        })
+        .collect()
+}
+
+/// Matches either an item ID or a case-insensitive item name.
+fn find_collectible_id(
+    name: &str,
+    collectible_ids: &[usize],
+    isaac_version: IsaacVersion,
+) -> Option<usize> {
+    if let Ok(id) = name.parse::<usize>() {
+        return collectible_ids.contains(&id).then_some(id);
+    }
+
+    collectible_ids
+        .iter()
+        .copied()
+        .find(|id| get_collectible_name(*id, isaac_version).eq_ignore_ascii_case(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        install::get_save_file_bytes,
+        persistent_game_data::{parse_persistent_game_data, PersistentGameData},
+        test_utils::FakeTree,
+    };
+
+    #[test]
+    fn limits_the_items_to_each_version() {
+        for isaac_version in (0..).map_while(IsaacVersion::from_repr) {
+            let last_collectible_id = get_last_collectible_id(isaac_version);
+            let collectible_ids = get_collectible_ids(COLLECTIBLE_NAMES.len(), isaac_version);
+            assert_eq!(collectible_ids.last(), Some(&last_collectible_id));
+        }
+
+        assert_eq!(get_collectible_name(347, IsaacVersion::Rebirth), "");
+        assert_eq!(
+            get_collectible_name(347, IsaacVersion::Afterbirth),
+            "Diplopia"
+        );
+        assert_eq!(
+            get_collectible_name(CLEAR_RUNE_ID, IsaacVersion::AfterbirthPlusBP5),
+            ""
+        );
+        assert_eq!(
+            get_collectible_name(CLEAR_RUNE_ID, IsaacVersion::Repentance),
+            "Clear Rune"
+        );
+    }
+
+    #[test]
+    fn fully_unlocked_saves_have_every_item() {
+        // The Rebirth and Afterbirth saves do not fill in the collection page, and the Afterbirth+
+        // save is from before Booster Pack 4.
+        for isaac_version in [
+            IsaacVersion::AfterbirthPlusBP5,
+            IsaacVersion::Repentance,
+            IsaacVersion::RepentancePlus,
+        ] {
+            let persistent_game_data =
+                PersistentGameData::from_bytes(&get_save_file_bytes(isaac_version).unwrap())
+                    .unwrap();
+            let collectibles = &persistent_game_data.collectibles;
+            for id in get_collectible_ids(collectibles.len(), isaac_version) {
+                assert_ne!(
+                    collectibles[id], 0,
+                    "{} is missing in {}",
+                    id, isaac_version
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn finds_items_by_name_or_id() {
+        let collectible_ids = get_collectible_ids(COLLECTIBLE_NAMES.len(), IsaacVersion::Rebirth);
+        let find = |name| find_collectible_id(name, &collectible_ids, IsaacVersion::Rebirth);
+
+        assert_eq!(find("the sad onion"), Some(1));
+        assert_eq!(find("2"), Some(2));
+        assert_eq!(find("347"), None);
+        assert_eq!(find("Diplopia"), None);
+    }
+
+    #[test]
+    fn marks_items_as_collected_and_uncollected() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+
+        // Reset slot 1, and then collect two items.
+        fake_tree
+            .run(&["y", "5", "13", "1", "7", "1", "4", "The Sad Onion, 2", "19"])
+            .unwrap();
+        let collectibles = parse_persistent_game_data(&save_file_path)
+            .unwrap()
+            .collectibles;
+        assert_eq!(collectibles[1], 1);
+        assert_eq!(collectibles[2], 1);
+        assert_eq!(collectibles[3], 0);
+
+        // Collect everything, and then uncollect one item.
+        fake_tree
+            .run(&["y", "5", "7", "1", "2", "7", "1", "5", "2", "19"])
+            .unwrap();
+        let collectibles = parse_persistent_game_data(&save_file_path)
+            .unwrap()
+            .collectibles;
+        let collectible_ids = get_collectible_ids(collectibles.len(), IsaacVersion::Repentance);
+        assert_eq!(collectibles[2], 0);
+        assert!(collectible_ids
+            .iter()
+            .filter(|id| **id != 2)
+            .all(|id| collectibles[*id] == 1));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    backup_store::RetentionPolicy,
+    constants::{CONFIG_DIR_NAME, CONFIG_FILE_NAME},
+    dry_run::{is_dry_run, write_file},
+    enums::IsaacVersion,
+    sandbox::get_sandbox_root,
+    save_data_path::{set_save_data_path_overrides, SaveDataPathOverrides},
+    utils::get_dir_of_running_exe,
+};
+use anyhow::{Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::fs::{create_dir_all, read_to_string};
+
+/// The settings that are remembered between runs of the program.
+#[derive(Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Config {
+    pub documents_dir: Option<Utf8PathBuf>,
+    pub steam_dir: Option<Utf8PathBuf>,
+    pub steam_user_id: Option<u32>,
+    pub last_isaac_version: Option<IsaacVersion>,
+    pub last_save_file_slot: Option<usize>,
+    /// Which backups are kept when the backups are pruned.
+    pub retention: RetentionPolicy,
+    /// A directory of save templates, in addition to the "saves" directory next to this program.
+    pub templates_dir: Option<Utf8PathBuf>,
+}
+
+impl Config {
+    pub fn get_save_data_path_overrides(&self) -> SaveDataPathOverrides {
+        SaveDataPathOverrides {
+            documents_dir: self.documents_dir.clone(),
+            steam_dir: self.steam_dir.clone(),
+            steam_user_id: self.steam_user_id,
+        }
+    }
+}
+
+/// A config file next to this program is used if it exists, so that the program can be portable.
+/// Otherwise, the config file is in the user config directory. The other files that this program
+/// keeps between runs go in the same directory.
+pub fn get_config_dir() -> Result<Utf8PathBuf> {
+    let exe_dir = get_dir_of_running_exe()?;
+    if exe_dir.join(CONFIG_FILE_NAME).exists() || get_sandbox_root().is_some() {
+        return Ok(exe_dir);
+    }
+
+    let config_dir = dirs_next::config_dir().and_then(|path| Utf8PathBuf::from_path_buf(path).ok());
+    match config_dir {
+        Some(config_dir) => Ok(config_dir.join(CONFIG_DIR_NAME)),
+        None => Ok(exe_dir),
+    }
+}
+
+fn get_config_path() -> Result<Utf8PathBuf> {
+    Ok(get_config_dir()?.join(CONFIG_FILE_NAME))
+}
+
+pub fn read_config() -> Result<Config> {
+    let config_path = get_config_path()?;
+    if !config_path.exists() {
+        return Ok(Config::default());
+    }
+
+    let config_json = read_to_string(&config_path).context(format!(
+        "Failed to read the file:\n{}",
+        config_path.to_string().green(),
+    ))?;
+    let mut config: Config = serde_json::from_str(&config_json).context(format!(
+        "Failed to parse the file:\n{}",
+        config_path.to_string().green(),
+    ))?;
+
+    // The file might have been edited by hand.
+    config.last_save_file_slot = config
+        .last_save_file_slot
+        .filter(|save_file_slot| (1..=3).contains(save_file_slot));
+
+    Ok(config)
+}
+
+pub fn write_config(config: &Config) -> Result<()> {
+    let config_path = get_config_path()?;
+    if !is_dry_run() {
+        if let Some(config_dir) = config_path.parent() {
+            create_dir_all(config_dir).context(format!(
+                "Failed to create the following directory:\n{}",
+                config_dir.to_string().green(),
+            ))?;
+        }
+    }
+
+    let config_json =
+        serde_json::to_string_pretty(config).context("Failed to serialize the config.")?;
+    write_file(&config_path, config_json)
+}
+
+/// The last game and save slot are not worth mentioning in a dry run, so they are just not saved.
+pub fn update_config(update: impl FnOnce(&mut Config)) -> Result<()> {
+    if is_dry_run() {
+        return Ok(());
+    }
+
+    let mut config = read_config()?;
+    update(&mut config);
+    write_config(&config)
+}
+
+/// Uses the paths from the config file, unless they are given on the command line.
+pub fn apply_save_data_path_overrides(command_line_overrides: SaveDataPathOverrides) -> Result<()> {
+    let config_overrides = read_config()?.get_save_data_path_overrides();
+    set_save_data_path_overrides(SaveDataPathOverrides {
+        documents_dir: command_line_overrides
+            .documents_dir
+            .or(config_overrides.documents_dir),
+        steam_dir: command_line_overrides
+            .steam_dir
+            .or(config_overrides.steam_dir),
+        steam_user_id: command_line_overrides
+            .steam_user_id
+            .or(config_overrides.steam_user_id),
+    });
+
+    Ok(())
+}
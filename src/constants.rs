@@ -1,3 +1,17 @@
 pub const OPTIONS_INI: &str = "options.ini";
 pub const OPTIONS_SECTION_NAME: &str = "Options";
 pub const STEAM_CLOUD_NAME: &str = "SteamCloud";
+pub const SAVE_TEMPLATES_DIR_NAME: &str = "saves";
+pub const SAVE_TEMPLATES_DIR_ENV_VAR: &str = "ISAAC_SAVE_TEMPLATES_DIR";
+pub const SAVE_TEMPLATE_MANIFEST: &str = "manifest.json";
+pub const SAVE_TEMPLATE_FILE_NAME: &str = "persistentgamedata.dat";
+pub const SANDBOX_ROOT_ENV_VAR: &str = "ISAAC_SAVE_INSTALLER_ROOT";
+pub const SANDBOX_REGISTRY_FILE_NAME: &str = "registry.ini";
+pub const SANDBOX_EXE_DIR_NAME: &str = "isaac-save-installer";
+pub const CONFIG_FILE_NAME: &str = "isaac-save-installer.json";
+pub const CONFIG_DIR_NAME: &str = "isaac-save-installer";
+pub const HISTORY_FILE_NAME: &str = "history.jsonl";
+pub const SNAPSHOTS_DIR_NAME: &str = "snapshots";
+pub const BACKUPS_DIR_NAME: &str = "backups";
+pub const BACKUP_INDEX_FILE_NAME: &str = "index.json";
+pub const BACKUP_OBJECTS_DIR_NAME: &str = "objects";
@@ -0,0 +1,383 @@
+use crate::{
+    backup::backup_before_overwrite,
+    dry_run::get_success_prefix,
+    enums::{IsaacVersion, Operation},
+    errors::{ErrorKind, InstallerError},
+    get_input::{confirm_convert_in_place, prompt_for_conversion_isaac_version},
+    history::{record_operation, FileState},
+    install::get_save_file_bytes,
+    isaac_save_installer::get_existing_save_files,
+    persistent_game_data::{
+        read_persistent_game_data, write_persistent_game_data, PersistentGameData,
+    },
+    progress::{
+        get_repentance_completion_mark, get_repentance_plus_completion_mark,
+        is_completion_mark_counter,
+    },
+    save_data_path::{
+        get_documents_save_data_path, get_steam_cloud_enabled, get_steam_save_data_path,
+    },
+    statistics::{get_counter_index, get_counters},
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+
+pub fn convert(
+    (save_file_path, exists): &(Utf8PathBuf, bool),
+    save_file_slot: usize,
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    if !exists {
+        bail!(InstallerError::new(
+            ErrorKind::SlotEmpty,
+            format!(
+                "You cannot convert the save file for slot {} since the corresponding file does not exist.",
+                save_file_slot,
+            ),
+        ));
+    }
+
+    let persistent_game_data = read_persistent_game_data(save_file_path, isaac_version)?;
+    let target_isaac_version = prompt_for_conversion_isaac_version(isaac_version)?;
+    let converted_persistent_game_data =
+        convert_persistent_game_data(&persistent_game_data, target_isaac_version)?;
+
+    let target_save_file_path = get_target_save_file_path(target_isaac_version, save_file_slot)?;
+    if target_save_file_path == *save_file_path {
+        let confirmed = confirm_convert_in_place(save_file_path)?;
+        if !confirmed {
+            return Ok(());
+        }
+        backup_before_overwrite(save_file_path)?;
+    } else if target_save_file_path.exists() {
+        bail!(
+            "You cannot convert the save file to slot {} of {} because the following file already exists. Backup and delete it first:\n{}",
+            save_file_slot,
+            target_isaac_version,
+            target_save_file_path.to_string().green(),
+        );
+    }
+
+    let state_before = FileState::read(&target_save_file_path)?;
+    write_persistent_game_data(&target_save_file_path, &converted_persistent_game_data)?;
+    record_operation(
+        Operation::Convert,
+        Some(save_file_path),
+        &target_save_file_path,
+        state_before,
+    )?;
+
+    println!(
+        "{} the {} save file to {}:\n{}\n-->\n{}",
+        get_success_prefix("converted", "convert"),
+        isaac_version,
+        target_isaac_version,
+        save_file_path.to_string().green(),
+        target_save_file_path.to_string().green(),
+    );
+
+    Ok(())
+}
+
+/// The converted file goes into the same slot of the other game.
+fn get_target_save_file_path(
+    target_isaac_version: IsaacVersion,
+    save_file_slot: usize,
+) -> Result<Utf8PathBuf> {
+    let documents_save_data_path = get_documents_save_data_path(target_isaac_version)?;
+    let steam_cloud_enabled = get_steam_cloud_enabled(&documents_save_data_path)?;
+    let save_data_path = match steam_cloud_enabled {
+        true => get_steam_save_data_path()?,
+        false => documents_save_data_path,
+    };
+
+    let existing_save_files =
+        get_existing_save_files(target_isaac_version, &save_data_path, steam_cloud_enabled);
+    let save_file_index = save_file_slot - 1;
+    let (save_file_path, _) = existing_save_files.get(save_file_index).context(format!(
+        "Failed to get the save file at index: {}",
+        save_file_index
+    ))?;
+
+    Ok(save_file_path.clone())
+}
+
+/// Every section is indexed by the in-game ID, and the IDs stay the same between versions, so the
+/// data is copied over by index. The layout of the target version is taken from its embedded save
+/// file, and anything that the source version does not have is left at the default of 0.
+///
+/// Converting to an older version only works if nothing would be lost.
+fn convert_persistent_game_data(
+    persistent_game_data: &PersistentGameData,
+    target_isaac_version: IsaacVersion,
+) -> Result<PersistentGameData> {
+    let isaac_version = persistent_game_data
+        .get_isaac_version()
+        .context("Failed to determine which version of the game the save file is for.")?;
+    if isaac_version == target_isaac_version {
+        bail!("The save file is already for {}.", target_isaac_version);
+    }
+    if isaac_version == IsaacVersion::Rebirth || target_isaac_version == IsaacVersion::Rebirth {
+        bail!("Rebirth save files use a different format, so they cannot be converted.");
+    }
+
+    let template = PersistentGameData::from_bytes(&get_save_file_bytes(target_isaac_version)?)
+        .context("Failed to parse the embedded save file.")?;
+    let source = persistent_game_data;
+    let mut converted = template.clone();
+    let mut lost = Vec::new();
+
+    converted.achievements = resize(
+        &source.achievements,
+        &template.achievements,
+        "achievements",
+        &mut lost,
+    );
+    converted.counters = convert_counters(
+        source,
+        &template,
+        isaac_version,
+        target_isaac_version,
+        &mut lost,
+    );
+    converted.level_counters = resize(
+        &source.level_counters,
+        &template.level_counters,
+        "level counters",
+        &mut lost,
+    );
+    converted.collectibles = resize(
+        &source.collectibles,
+        &template.collectibles,
+        "collection page",
+        &mut lost,
+    );
+    converted.minibosses = resize(
+        &source.minibosses,
+        &template.minibosses,
+        "minibosses",
+        &mut lost,
+    );
+    converted.bosses = resize(&source.bosses, &template.bosses, "bosses", &mut lost);
+    converted.challenges = resize(
+        &source.challenges,
+        &template.challenges,
+        "challenges",
+        &mut lost,
+    );
+    converted.cutscene_counters = resize(
+        &source.cutscene_counters,
+        &template.cutscene_counters,
+        "cutscenes",
+        &mut lost,
+    );
+    converted.game_settings = resize(
+        &source.game_settings,
+        &template.game_settings,
+        "game settings",
+        &mut lost,
+    );
+    converted.special_seeds = template
+        .special_seeds
+        .as_ref()
+        .map(|template_special_seeds| {
+            let special_seeds = source.special_seeds.as_deref().unwrap_or_default();
+            resize(
+                special_seeds,
+                template_special_seeds,
+                "special seeds",
+                &mut lost,
+            )
+        });
+
+    let source_bestiary_lists = source.bestiary.iter().flat_map(|bestiary| &bestiary.lists);
+    match &mut converted.bestiary {
+        Some(bestiary) => {
+            for list in &mut bestiary.lists {
+                list.entries = source_bestiary_lists
+                    .clone()
+                    .filter(|source_list| source_list.list_type == list.list_type)
+                    .flat_map(|source_list| source_list.entries.clone())
+                    .collect();
+            }
+        }
+        None => {
+            if source_bestiary_lists
+                .clone()
+                .any(|list| !list.entries.is_empty())
+            {
+                lost.push("bestiary".to_string());
+            }
+        }
+    }
+
+    if !lost.is_empty() {
+        bail!(
+            "Converting the save file from {} to {} would lose the following data: {}",
+            isaac_version,
+            target_isaac_version,
+            lost.join(", "),
+        );
+    }
+
+    Ok(converted)
+}
+
+/// Copies the values into a list with the length of the template list, recording a loss if a value
+/// that is not 0 does not fit.
+fn resize<T: Copy + Default + PartialEq>(
+    values: &[T],
+    template: &[T],
+    name: &str,
+    lost: &mut Vec<String>,
+) -> Vec<T> {
+    if values
+        .iter()
+        .skip(template.len())
+        .any(|value| *value != T::default())
+    {
+        lost.push(name.to_string());
+    }
+
+    let mut resized = vec![T::default(); template.len()];
+    for (resized_value, value) in resized.iter_mut().zip(values) {
+        *resized_value = *value;
+    }
+
+    resized
+}
+
+fn convert_counters(
+    source: &PersistentGameData,
+    template: &PersistentGameData,
+    isaac_version: IsaacVersion,
+    target_isaac_version: IsaacVersion,
+    lost: &mut Vec<String>,
+) -> Vec<u32> {
+    let mut counters = resize(&source.counters, &template.counters, "statistics", lost);
+
+    // Some counters moved between versions.
+    for counter in get_counters(isaac_version) {
+        let (Some(index), Some(target_index)) = (
+            get_counter_index(counter, isaac_version),
+            get_counter_index(counter, target_isaac_version),
+        ) else {
+            continue;
+        };
+        if index != target_index {
+            if counters[target_index] != 0 {
+                lost.push(format!("counter #{}", target_index));
+            }
+            counters[target_index] = source.counters.get(index).copied().unwrap_or_default();
+            if index < counters.len() {
+                counters[index] = 0;
+            }
+        }
+    }
+
+    // Repentance+ stores the completion marks differently. A mark is lost when converting it back
+    // does not give the same bits.
+    let is_plus = |isaac_version| isaac_version == IsaacVersion::RepentancePlus;
+    if is_plus(isaac_version) != is_plus(target_isaac_version) {
+        let mut lost_completion_marks = false;
+        for (index, value) in counters.iter_mut().enumerate() {
+            if !is_completion_mark_counter(index, target_isaac_version) {
+                continue;
+            }
+
+            *value = match is_plus(target_isaac_version) {
+                true => get_repentance_plus_completion_mark(*value),
+                false => {
+                    let converted_value = get_repentance_completion_mark(*value);
+                    lost_completion_marks |=
+                        *value & !get_repentance_plus_completion_mark(converted_value) != 0;
+                    converted_value
+                }
+            };
+        }
+        if lost_completion_marks {
+            lost.push("completion marks".to_string());
+        }
+    }
+
+    counters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backup_store::{get_newest_backup_entry, read_backup_index},
+        history::get_hash,
+        persistent_game_data::parse_persistent_game_data,
+        test_utils::FakeTree,
+    };
+    use std::fs::write;
+
+    fn get_full_persistent_game_data(isaac_version: IsaacVersion) -> PersistentGameData {
+        PersistentGameData::from_bytes(&get_save_file_bytes(isaac_version).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn keeps_the_completion_marks_when_converting_to_a_newer_version() {
+        let persistent_game_data = get_full_persistent_game_data(IsaacVersion::AfterbirthPlus);
+        let converted =
+            convert_persistent_game_data(&persistent_game_data, IsaacVersion::Repentance).unwrap();
+
+        for (index, value) in persistent_game_data.counters.iter().enumerate() {
+            if is_completion_mark_counter(index, IsaacVersion::AfterbirthPlus) {
+                assert!(is_completion_mark_counter(index, IsaacVersion::Repentance));
+                assert_eq!(converted.counters[index], *value);
+            }
+        }
+    }
+
+    #[test]
+    fn converts_the_completion_marks_for_repentance_plus() {
+        let persistent_game_data = get_full_persistent_game_data(IsaacVersion::Repentance);
+        let converted =
+            convert_persistent_game_data(&persistent_game_data, IsaacVersion::RepentancePlus)
+                .unwrap();
+
+        // Every Hard mode mark has the same bits as the fully-unlocked Repentance+ save file.
+        let full_persistent_game_data = get_full_persistent_game_data(IsaacVersion::RepentancePlus);
+        for (index, value) in converted.counters.iter().enumerate() {
+            if is_completion_mark_counter(index, IsaacVersion::RepentancePlus) {
+                assert_eq!(
+                    *value, full_persistent_game_data.counters[index],
+                    "counter #{}",
+                    index
+                );
+            }
+        }
+
+        // Converting back gives the same marks.
+        let converted_back =
+            convert_persistent_game_data(&converted, IsaacVersion::Repentance).unwrap();
+        assert_eq!(converted_back.counters, persistent_game_data.counters);
+    }
+
+    #[test]
+    fn backs_up_a_save_file_before_converting_it_in_place() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::AfterbirthPlus, 1);
+        let bytes = get_save_file_bytes(IsaacVersion::AfterbirthPlus).unwrap();
+        write(&save_file_path, &bytes).unwrap();
+
+        // Afterbirth+ and Booster Pack 5 share a directory, so the file is converted in place.
+        fake_tree
+            .run(&["y", "3", "12", "1", "4", "y", "19"])
+            .unwrap();
+
+        let backup_entries = read_backup_index().unwrap();
+        let backup_entry = get_newest_backup_entry(&backup_entries, &save_file_path).unwrap();
+        assert_eq!(backup_entry.hash, get_hash(&bytes));
+        assert!(
+            parse_persistent_game_data(&save_file_path)
+                .unwrap()
+                .get_isaac_version()
+                == Some(IsaacVersion::AfterbirthPlusBP5)
+        );
+    }
+}
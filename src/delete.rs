@@ -1,19 +1,27 @@
 use anyhow::{bail, Context, Result};
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use colored::*;
-use std::fs::remove_file;
 
-use crate::utils::get_dir_of_running_exe;
+use crate::{
+    dry_run::{delete_file, get_success_prefix},
+    enums::Operation,
+    errors::{ErrorKind, InstallerError},
+    history::{record_operation, FileState},
+    utils::get_dir_of_running_exe,
+};
 
 pub fn delete(
     (existing_save_file_path, exists): &(Utf8PathBuf, bool),
     save_file_slot: usize,
 ) -> Result<()> {
     if !exists {
-        bail!(
-            "You cannot delete a save file for slot {} since the corresponding file does not exist.",
-            save_file_slot,
-        );
+        bail!(InstallerError::new(
+            ErrorKind::SlotEmpty,
+            format!(
+                "You cannot delete a save file for slot {} since the corresponding file does not exist.",
+                save_file_slot,
+            ),
+        ));
     }
 
     let _dir_path = get_dir_of_running_exe()?;
@@ -22,15 +30,19 @@ pub fn delete(
         existing_save_file_path.to_string().green(),
     ))?;
 
-    remove_file(existing_save_file_path).context(format!(
-        "Failed to delete:\n{}",
-        existing_save_file_path.to_string().green(),
-    ))?;
+    delete_save_file(existing_save_file_path)?;
 
     println!(
-        "Successfully deleted:\n{}",
+        "{}:\n{}",
+        get_success_prefix("deleted", "delete"),
         existing_save_file_path.to_string().green(),
     );
 
     Ok(())
 }
+
+pub fn delete_save_file(save_file_path: &Utf8Path) -> Result<()> {
+    let state_before = FileState::read(save_file_path)?;
+    delete_file(save_file_path)?;
+    record_operation(Operation::Delete, None, save_file_path, state_before)
+}
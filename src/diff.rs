@@ -0,0 +1,366 @@
+use crate::{
+    achievements::AchievementTable,
+    backup_store::{get_backup_object_path, get_newest_backup_entry, read_backup_index},
+    challenges::get_challenge_name,
+    collection::get_collectible_name,
+    enums::{Counter, IsaacVersion},
+    get_input::prompt_for_save_file_to_compare,
+    persistent_game_data::{parse_persistent_game_data, PersistentGameData},
+    progress::is_completion_mark_counter,
+    statistics::{get_counter_index, get_counters, get_display_value},
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+
+pub fn diff(existing_save_files: &[(Utf8PathBuf, bool)]) -> Result<()> {
+    let old_save_file_path = get_save_file_path_to_compare("first", existing_save_files)?;
+    let new_save_file_path = get_save_file_path_to_compare("second", existing_save_files)?;
+    let old = parse_persistent_game_data(&old_save_file_path)?;
+    let new = parse_persistent_game_data(&new_save_file_path)?;
+
+    println!("Comparing:");
+    println!("{}", describe_save_file(&old_save_file_path, &old));
+    println!("-->");
+    println!("{}", describe_save_file(&new_save_file_path, &new));
+    println!();
+
+    // The names are only meaningful when both files use the same layout. Otherwise, the names of the
+    // newest version are used, since every version only adds to the ones before it.
+    let isaac_version = get_common_isaac_version(&old, &new);
+    let names_version = isaac_version.unwrap_or(IsaacVersion::RepentancePlus);
+    let achievement_table = AchievementTable::read(names_version);
+
+    let mut num_differences = 0;
+    num_differences +=
+        print_flag_differences("Achievements", &old.achievements, &new.achievements, |id| {
+            achievement_table.describe(id)
+        });
+    num_differences += print_counter_differences(&old, &new, isaac_version);
+    num_differences +=
+        print_flag_differences("Challenges", &old.challenges, &new.challenges, |id| {
+            format!("{}) {}", id, get_challenge_name(id))
+        });
+    num_differences += print_flag_differences(
+        "Collection page",
+        &old.collectibles,
+        &new.collectibles,
+        |id| format!("{}) {}", id, get_collectible_name(id, names_version)),
+    );
+
+    match num_differences {
+        0 => println!("There are no differences between the two save files."),
+        _ => println!(
+            "There are {} difference(s) between the two save files.",
+            num_differences.to_string().green(),
+        ),
+    }
+
+    Ok(())
+}
+
+/// Resolves a slot number, the newest backup of a slot, or a path to the path of a save file.
+fn get_save_file_path_to_compare(
+    ordinal: &str,
+    existing_save_files: &[(Utf8PathBuf, bool)],
+) -> Result<Utf8PathBuf> {
+    let input = prompt_for_save_file_to_compare(ordinal)?;
+
+    let save_file_path = if let Some(slot) = parse_slot(&input) {
+        let (save_file_path, _) = get_save_file(slot, existing_save_files)?;
+        save_file_path.clone()
+    } else if let Some(slot) = input
+        .strip_prefix("backup")
+        .and_then(|slot| parse_slot(slot.trim()))
+    {
+        let (save_file_path, _) = get_save_file(slot, existing_save_files)?;
+        let backup_entries = read_backup_index()?;
+        let backup_entry = get_newest_backup_entry(&backup_entries, save_file_path)
+            .context(format!("There are no backups of slot {} yet.", slot))?;
+        get_backup_object_path(&backup_entry.hash)?
+    } else {
+        Utf8PathBuf::from(input)
+    };
+
+    if !save_file_path.exists() {
+        bail!(
+            "You cannot compare the following save file since it does not exist:\n{}",
+            save_file_path.to_string().green(),
+        );
+    }
+
+    Ok(save_file_path)
+}
+
+fn parse_slot(input: &str) -> Option<usize> {
+    input
+        .parse::<usize>()
+        .ok()
+        .filter(|slot| (1..=3).contains(slot))
+}
+
+fn get_save_file(
+    save_file_slot: usize,
+    existing_save_files: &[(Utf8PathBuf, bool)],
+) -> Result<&(Utf8PathBuf, bool)> {
+    let save_file_index = save_file_slot - 1;
+    existing_save_files.get(save_file_index).context(format!(
+        "Failed to get the save file at index: {}",
+        save_file_index
+    ))
+}
+
+fn get_common_isaac_version(
+    old: &PersistentGameData,
+    new: &PersistentGameData,
+) -> Option<IsaacVersion> {
+    match (old.get_isaac_version(), new.get_isaac_version()) {
+        (Some(old_version), Some(new_version)) if old_version == new_version => Some(old_version),
+        _ => None,
+    }
+}
+
+fn describe_save_file(save_file_path: &Utf8PathBuf, data: &PersistentGameData) -> String {
+    let version = match data.get_isaac_version() {
+        Some(isaac_version) => isaac_version.to_string(),
+        None => "unknown version".to_string(),
+    };
+
+    format!("{} ({})", save_file_path.to_string().green(), version)
+}
+
+/// Returns the number of differences.
+fn print_flag_differences(
+    category: &str,
+    old: &[u8],
+    new: &[u8],
+    describe: impl Fn(usize) -> String,
+) -> usize {
+    let (gained, lost) = get_flag_differences(old, new);
+
+    if !gained.is_empty() {
+        println!("{} gained:", category);
+        for id in &gained {
+            println!("{} {}", "+".green(), describe(*id));
+        }
+        println!();
+    }
+    if !lost.is_empty() {
+        println!("{} lost:", category);
+        for id in &lost {
+            println!("{} {}", "-".red(), describe(*id));
+        }
+        println!();
+    }
+
+    gained.len() + lost.len()
+}
+
+/// Returns the IDs that were gained and the IDs that were lost.
+fn get_flag_differences(old: &[u8], new: &[u8]) -> (Vec<usize>, Vec<usize>) {
+    let len = old.len().max(new.len());
+    (1..len)
+        .filter(|id| is_set(old, *id) != is_set(new, *id))
+        .partition(|id| is_set(new, *id))
+}
+
+fn is_set(flags: &[u8], id: usize) -> bool {
+    flags.get(id).is_some_and(|flag| *flag != 0)
+}
+
+/// Returns the number of differences.
+fn print_counter_differences(
+    old: &PersistentGameData,
+    new: &PersistentGameData,
+    isaac_version: Option<IsaacVersion>,
+) -> usize {
+    let (marks, statistics) = get_counter_differences(old, new, isaac_version);
+
+    if !marks.is_empty() {
+        println!("Completion marks changed:");
+        for (index, old_value, new_value) in &marks {
+            println!(
+                "{} Completion mark (counter #{}): {} -> {}",
+                "*".yellow(),
+                index,
+                old_value,
+                new_value.to_string().green(),
+            );
+        }
+        println!();
+    }
+    if !statistics.is_empty() {
+        println!("Statistics changed:");
+        for (index, old_value, new_value) in &statistics {
+            let (name, old_value, new_value) =
+                match isaac_version.and_then(|isaac_version| get_counter(*index, isaac_version)) {
+                    Some(counter) => (
+                        counter.to_string(),
+                        get_display_value(counter, *old_value),
+                        get_display_value(counter, *new_value),
+                    ),
+                    None => (
+                        format!("Counter #{}", index),
+                        i64::from(*old_value),
+                        i64::from(*new_value),
+                    ),
+                };
+            println!(
+                "{} {}: {} -> {}",
+                "*".yellow(),
+                name,
+                old_value,
+                new_value.to_string().green(),
+            );
+        }
+        println!();
+    }
+
+    marks.len() + statistics.len()
+}
+
+type CounterDifference = (usize, u32, u32);
+
+/// Returns the changed completion marks and the changed statistics, as the index, the old value, and
+/// the new value. The completion marks are only told apart when both files use the same layout.
+fn get_counter_differences(
+    old: &PersistentGameData,
+    new: &PersistentGameData,
+    isaac_version: Option<IsaacVersion>,
+) -> (Vec<CounterDifference>, Vec<CounterDifference>) {
+    let len = old.counters.len().max(new.counters.len());
+    (1..len)
+        .filter_map(|index| {
+            let old_value = old.counters.get(index).copied().unwrap_or_default();
+            let new_value = new.counters.get(index).copied().unwrap_or_default();
+            (old_value != new_value).then_some((index, old_value, new_value))
+        })
+        .partition(|(index, _, _)| {
+            isaac_version
+                .is_some_and(|isaac_version| is_completion_mark_counter(*index, isaac_version))
+        })
+}
+
+fn get_counter(index: usize, isaac_version: IsaacVersion) -> Option<Counter> {
+    get_counters(isaac_version)
+        .into_iter()
+        .find(|counter| get_counter_index(*counter, isaac_version) == Some(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backup_store::store_backup,
+        get_input::{set_input, ScriptedInput},
+        history::get_hash,
+        install::get_save_file_bytes,
+        isaac_save_installer::get_existing_save_files,
+        save_data_path::get_documents_save_data_path,
+        test_utils::FakeTree,
+    };
+    use std::fs::write;
+
+    fn get_save_file_path_for_answer(
+        answer: &str,
+        existing_save_files: &[(Utf8PathBuf, bool)],
+    ) -> Result<Utf8PathBuf> {
+        set_input(Box::new(ScriptedInput::new(vec![answer.to_string()])));
+        get_save_file_path_to_compare("first", existing_save_files)
+    }
+
+    #[test]
+    fn uses_the_newest_backup_of_a_slot() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        let documents_save_data_path =
+            get_documents_save_data_path(IsaacVersion::Repentance).unwrap();
+        let existing_save_files =
+            get_existing_save_files(IsaacVersion::Repentance, &documents_save_data_path, false);
+
+        write(&save_file_path, b"first session").unwrap();
+        store_backup(&save_file_path).unwrap();
+        write(&save_file_path, b"second session").unwrap();
+        store_backup(&save_file_path).unwrap();
+        write(&save_file_path, b"third session").unwrap();
+
+        assert_eq!(
+            get_save_file_path_for_answer("backup 1", &existing_save_files).unwrap(),
+            get_backup_object_path(&get_hash(b"second session")).unwrap(),
+        );
+        assert!(get_save_file_path_for_answer("backup 2", &existing_save_files).is_err());
+    }
+
+    /// Two copies of the fully-unlocked save file, where each copy is missing a different
+    /// achievement, and the second copy has more wins and is missing a completion mark.
+    fn get_modified_copies() -> (PersistentGameData, PersistentGameData, usize) {
+        let bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        let mut old = PersistentGameData::from_bytes(&bytes).unwrap();
+        let mut new = PersistentGameData::from_bytes(&bytes).unwrap();
+        let wins_index = get_counter_index(Counter::Wins, IsaacVersion::Repentance).unwrap();
+
+        old.achievements[5] = 0;
+        new.achievements[7] = 0;
+        new.counters[wins_index] += 3;
+        new.counters[27] = 0;
+
+        (old, new, wins_index)
+    }
+
+    #[test]
+    fn finds_gained_and_lost_achievements_and_changed_statistics() {
+        let (old, new, wins_index) = get_modified_copies();
+        let isaac_version = get_common_isaac_version(&old, &new);
+        assert!(isaac_version == Some(IsaacVersion::Repentance));
+
+        assert_eq!(
+            get_flag_differences(&old.achievements, &new.achievements),
+            (vec![5], vec![7]),
+        );
+        let (marks, statistics) = get_counter_differences(&old, &new, isaac_version);
+        assert_eq!(marks, vec![(27, old.counters[27], 0)]);
+        assert_eq!(
+            statistics,
+            vec![(
+                wins_index,
+                old.counters[wins_index],
+                old.counters[wins_index] + 3
+            )],
+        );
+        assert!(get_counter(wins_index, IsaacVersion::Repentance) == Some(Counter::Wins));
+    }
+
+    #[test]
+    fn does_not_name_counters_of_different_versions() {
+        let (old, _, _) = get_modified_copies();
+        let new = PersistentGameData::from_bytes(
+            &get_save_file_bytes(IsaacVersion::RepentancePlus).unwrap(),
+        )
+        .unwrap();
+        let isaac_version = get_common_isaac_version(&old, &new);
+        assert!(isaac_version.is_none());
+
+        // Without a common layout, the completion marks are not told apart from the statistics.
+        let (marks, statistics) = get_counter_differences(&old, &new, isaac_version);
+        assert!(marks.is_empty());
+        assert!(!statistics.is_empty());
+    }
+
+    #[test]
+    fn compares_two_slots() {
+        let fake_tree = FakeTree::new();
+        let (old, new, _) = get_modified_copies();
+        write(
+            fake_tree.get_save_file_path(IsaacVersion::Repentance, 1),
+            old.to_bytes(),
+        )
+        .unwrap();
+        write(
+            fake_tree.get_save_file_path(IsaacVersion::Repentance, 2),
+            new.to_bytes(),
+        )
+        .unwrap();
+
+        fake_tree.run(&["y", "5", "11", "1", "2", "19"]).unwrap();
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{
+    persistent_game_data::PersistentGameData,
+    settings::{get_settings, update_settings},
+};
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use colored::*;
+use std::{
+    fs::{copy, read, remove_file, write},
+    mem::take,
+    sync::{Mutex, PoisonError},
+};
+
+/// In a dry run, every change to a file is recorded here instead of being made, so that the user can
+/// see exactly what would happen.
+static DRY_RUN_ACTIONS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+pub fn set_dry_run(dry_run: bool) {
+    update_settings(|settings| settings.dry_run = dry_run);
+}
+
+pub fn is_dry_run() -> bool {
+    get_settings().dry_run
+}
+
+pub fn add_dry_run_action(action: String) {
+    DRY_RUN_ACTIONS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(action);
+}
+
+/// Returns the changes that would have been made since the last call.
+pub fn take_dry_run_actions() -> Vec<String> {
+    take(
+        &mut *DRY_RUN_ACTIONS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner),
+    )
+}
+
+/// In a dry run, nothing was changed, so the message says what would have been done instead, e.g.
+/// "Would install" instead of "Successfully installed".
+pub fn get_success_prefix(past_tense: &str, base_form: &str) -> String {
+    match is_dry_run() {
+        true => format!("Would {}", base_form),
+        false => format!("Successfully {}", past_tense),
+    }
+}
+
+pub fn print_dry_run_mode() {
+    match is_dry_run() {
+        true => println!(
+            "{} Nothing will be changed. You will be shown what would have been changed instead.",
+            "Dry run:".yellow(),
+        ),
+        false => println!("Dry-run mode is off. Files will be changed again."),
+    }
+    println!();
+}
+
+pub fn print_dry_run_actions() {
+    if !is_dry_run() {
+        return;
+    }
+
+    let actions = take_dry_run_actions();
+    println!(
+        "{} Nothing was changed, since this is a dry run.",
+        "Dry run:".yellow(),
+    );
+    if actions.is_empty() {
+        println!("No files would have been changed.");
+        return;
+    }
+
+    println!("These are the changes that would have been made:");
+    for (i, action) in actions.iter().enumerate() {
+        println!("{}) {}", i + 1, action);
+    }
+}
+
+pub fn write_file(path: &Utf8Path, contents: impl AsRef<[u8]>) -> Result<()> {
+    if is_dry_run() {
+        add_dry_run_action(format!(
+            "Write {} bytes to:\n{}\n{}",
+            contents.as_ref().len(),
+            path.to_string().green(),
+            describe_file(path),
+        ));
+        return Ok(());
+    }
+
+    write(path, contents).context(format!(
+        "Failed to write data to the following path:\n{}",
+        path.to_string().green(),
+    ))
+}
+
+pub fn copy_file(source_path: &Utf8Path, destination_path: &Utf8Path) -> Result<()> {
+    if is_dry_run() {
+        add_dry_run_action(format!(
+            "Copy:\n{}\n-->\n{}\n{}",
+            source_path.to_string().green(),
+            destination_path.to_string().green(),
+            describe_file(destination_path),
+        ));
+        return Ok(());
+    }
+
+    copy(source_path, destination_path).context(format!(
+        "Failed to copy:\n{}\n-->\n{}",
+        source_path.to_string().green(),
+        destination_path.to_string().green(),
+    ))?;
+
+    Ok(())
+}
+
+pub fn delete_file(path: &Utf8Path) -> Result<()> {
+    if is_dry_run() {
+        add_dry_run_action(format!(
+            "Delete:\n{}\n{}",
+            path.to_string().green(),
+            describe_file(path),
+        ));
+        return Ok(());
+    }
+
+    remove_file(path).context(format!("Failed to delete:\n{}", path.to_string().green()))
+}
+
+/// Save files are parsed, so that the user can see what would be overwritten.
+fn describe_file(path: &Utf8Path) -> String {
+    if !path.exists() {
+        return "(This file does not exist yet.)".to_string();
+    }
+    if path.extension() != Some("dat") {
+        return "(This file already exists.)".to_string();
+    }
+
+    let persistent_game_data = read(path)
+        .context("Failed to read the file.")
+        .and_then(|bytes| PersistentGameData::from_bytes(&bytes));
+    match persistent_game_data {
+        Ok(persistent_game_data) => match persistent_game_data.get_isaac_version() {
+            Some(isaac_version) => format!("(This is currently a {} save file.)", isaac_version),
+            None => "(This is currently a save file for an unknown game.)".to_string(),
+        },
+        Err(err) => format!("(This is currently not a valid save file: {})", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        delete::delete_save_file,
+        enums::IsaacVersion,
+        history::read_history,
+        save_data_path::{get_documents_save_data_path, toggle_steam_cloud_enabled},
+        test_utils::{lock, FakeTree},
+    };
+    use std::fs::read_to_string;
+
+    #[test]
+    fn says_what_would_have_been_done() {
+        let _lock = lock();
+        assert_eq!(
+            get_success_prefix("installed", "install"),
+            "Successfully installed",
+        );
+
+        set_dry_run(true);
+        assert_eq!(get_success_prefix("installed", "install"), "Would install");
+    }
+
+    #[test]
+    fn dry_run_changes_nothing() {
+        let fake_tree = FakeTree::new();
+        fake_tree.run(&["y", "5", "1", "1", "19"]).unwrap();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        let save_file_bytes = read(&save_file_path).unwrap();
+        let options_ini_path = get_documents_save_data_path(IsaacVersion::Repentance)
+            .unwrap()
+            .join("options.ini");
+        let options_ini = read_to_string(&options_ini_path).unwrap();
+
+        // Turn on dry-run mode, and then reset, convert, delete, and turn on Steam Cloud.
+        fake_tree
+            .run(&[
+                "y", "5", "17", "13", "1", "12", "1", "6", "3", "1", "4", "y", "19",
+            ])
+            .unwrap();
+
+        assert!(is_dry_run());
+        assert_eq!(read(&save_file_path).unwrap(), save_file_bytes);
+        assert_eq!(read_to_string(&options_ini_path).unwrap(), options_ini);
+        assert_eq!(read_history().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn dry_run_shows_changes() {
+        let fake_tree = FakeTree::new();
+        fake_tree.run(&["y", "5", "1", "1", "19"]).unwrap();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        let documents_save_data_path =
+            get_documents_save_data_path(IsaacVersion::Repentance).unwrap();
+
+        set_dry_run(true);
+        delete_save_file(&save_file_path).unwrap();
+        toggle_steam_cloud_enabled(&documents_save_data_path, false).unwrap();
+        let actions = take_dry_run_actions();
+
+        assert!(save_file_path.exists());
+        assert_eq!(actions.len(), 2);
+        assert!(actions[0].starts_with("Delete:"));
+        assert!(actions[0].contains("This is currently a Repentance save file."));
+        assert!(actions[1].contains("SteamCloud=0 --> SteamCloud=1"));
+    }
+}
@@ -0,0 +1,483 @@
+/// The names of the entities that can appear in the bestiary, sorted by entity type and variant.
+/// Variants that are not listed use the name of variant 0.
+pub const ENTITY_NAMES: [(u32, u32, &str); 479] = [
+    (10, 0, "Frowning Gaper"),
+    (10, 1, "Gaper"),
+    (10, 2, "Flaming Gaper"),
+    (10, 3, "Rotten Gaper"),
+    (11, 0, "Gusher"),
+    (11, 1, "Pacer"),
+    (12, 0, "Horf"),
+    (13, 0, "Fly"),
+    (14, 0, "Pooter"),
+    (14, 1, "Super Pooter"),
+    (14, 2, "Tainted Pooter"),
+    (15, 0, "Clotty"),
+    (15, 1, "Clot"),
+    (15, 2, "I. Blob"),
+    (15, 3, "Grilled Clotty"),
+    (16, 0, "Mulligan"),
+    (16, 1, "Mulligoon"),
+    (16, 2, "Mulliboom"),
+    (18, 0, "Attack Fly"),
+    (19, 0, "Larry Jr."),
+    (19, 1, "The Hollow"),
+    (19, 2, "Tuff Twins"),
+    (19, 3, "The Shell"),
+    (20, 0, "Monstro"),
+    (21, 0, "Maggot"),
+    (22, 0, "Hive"),
+    (22, 1, "Drowned Hive"),
+    (22, 2, "Holy Mulligan"),
+    (22, 3, "Tainted Mulligan"),
+    (23, 0, "Charger"),
+    (23, 1, "Drowned Charger"),
+    (23, 2, "Dank Charger"),
+    (23, 3, "Carrion Princess"),
+    (24, 0, "Globin"),
+    (24, 1, "Gazing Globin"),
+    (24, 2, "Dank Globin"),
+    (24, 3, "Cursed Globin"),
+    (25, 0, "Boom Fly"),
+    (25, 1, "Red Boom Fly"),
+    (25, 2, "Drowned Boom Fly"),
+    (25, 3, "Dragon Fly"),
+    (25, 4, "Bone Fly"),
+    (25, 5, "Sick Boom Fly"),
+    (25, 6, "Tainted Boom Fly"),
+    (26, 0, "Maw"),
+    (26, 1, "Red Maw"),
+    (26, 2, "Psychic Maw"),
+    (27, 0, "Host"),
+    (27, 1, "Red Host"),
+    (27, 3, "Hard Host"),
+    (28, 0, "Chub"),
+    (28, 1, "C.H.A.D."),
+    (28, 2, "The Carrion Queen"),
+    (29, 0, "Hopper"),
+    (29, 1, "Trite"),
+    (29, 2, "Eggy"),
+    (29, 3, "Tainted Hopper"),
+    (30, 0, "Boil"),
+    (30, 1, "Gut"),
+    (30, 2, "Sack"),
+    (31, 0, "Spitty"),
+    (31, 1, "Tainted Spitty"),
+    (32, 0, "Brain"),
+    (33, 0, "Fire Place"),
+    (34, 0, "Leaper"),
+    (34, 1, "Sticky Leaper"),
+    (35, 0, "Mr. Maw"),
+    (35, 1, "Mr. Maw (Head)"),
+    (35, 2, "Mr. Red Maw"),
+    (35, 3, "Mr. Red Maw (Head)"),
+    (36, 0, "Gurdy"),
+    (38, 0, "Baby"),
+    (38, 1, "Angelic Baby"),
+    (38, 2, "Ultra Pride Baby"),
+    (38, 3, "Wrinkly Baby"),
+    (39, 0, "Vis"),
+    (39, 1, "Double Vis"),
+    (39, 2, "Chubber"),
+    (39, 3, "Scarred Double Vis"),
+    (40, 0, "Guts"),
+    (40, 1, "Scarred Guts"),
+    (40, 2, "Slog"),
+    (41, 0, "Knight"),
+    (41, 1, "Selfless Knight"),
+    (41, 2, "Loose Knight"),
+    (41, 3, "Brainless Knight"),
+    (41, 4, "Black Knight"),
+    (42, 0, "Stone Grimace"),
+    (42, 1, "Vomit Grimace"),
+    (42, 2, "Triple Grimace"),
+    (43, 0, "Monstro II"),
+    (43, 1, "Gish"),
+    (44, 0, "Poky"),
+    (44, 1, "Slide"),
+    (45, 0, "Mom"),
+    (46, 0, "Sloth"),
+    (46, 1, "Super Sloth"),
+    (46, 2, "Ultra Pride"),
+    (47, 0, "Lust"),
+    (47, 1, "Super Lust"),
+    (48, 0, "Wrath"),
+    (48, 1, "Super Wrath"),
+    (49, 0, "Gluttony"),
+    (49, 1, "Super Gluttony"),
+    (50, 0, "Greed"),
+    (50, 1, "Super Greed"),
+    (51, 0, "Envy"),
+    (51, 1, "Super Envy"),
+    (51, 10, "Envy (Medium)"),
+    (51, 11, "Super Envy (Medium)"),
+    (51, 20, "Envy (Small)"),
+    (51, 21, "Super Envy (Small)"),
+    (51, 30, "Envy (Tiny)"),
+    (51, 31, "Super Envy (Tiny)"),
+    (52, 0, "Pride"),
+    (52, 1, "Super Pride"),
+    (53, 0, "Dople"),
+    (53, 1, "Evil Twin"),
+    (54, 0, "Flaming Hopper"),
+    (55, 0, "Leech"),
+    (55, 1, "Kamikaze Leech"),
+    (55, 2, "Holy Leech"),
+    (56, 0, "Lump"),
+    (57, 0, "Membrain"),
+    (57, 1, "Mama Guts"),
+    (57, 2, "Dead Meat"),
+    (58, 0, "Para-Bite"),
+    (58, 1, "Scarred Para-Bite"),
+    (59, 0, "Fred"),
+    (60, 0, "Eye"),
+    (60, 1, "Bloodshot Eye"),
+    (60, 2, "Holy Eye"),
+    (61, 0, "Sucker"),
+    (61, 1, "Spit"),
+    (61, 2, "Soul Sucker"),
+    (61, 3, "Ink"),
+    (61, 4, "Mama Fly"),
+    (61, 5, "Bulb"),
+    (61, 6, "Bloodfly"),
+    (61, 7, "Tainted Sucker"),
+    (62, 0, "Pin"),
+    (62, 1, "Scolex"),
+    (62, 2, "The Frail"),
+    (62, 3, "Wormwood"),
+    (63, 0, "Famine"),
+    (64, 0, "Pestilence"),
+    (65, 0, "War"),
+    (65, 1, "Conquest"),
+    (66, 0, "Death"),
+    (67, 0, "Duke of Flies"),
+    (67, 1, "The Husk"),
+    (68, 0, "Peep"),
+    (68, 1, "The Bloat"),
+    (69, 0, "Loki"),
+    (69, 1, "Lokii"),
+    (71, 0, "Fistula (Big)"),
+    (71, 1, "Teratoma"),
+    (72, 0, "Fistula (Medium)"),
+    (73, 0, "Fistula (Small)"),
+    (74, 0, "Blastocyst (Big)"),
+    (75, 0, "Blastocyst (Medium)"),
+    (76, 0, "Blastocyst (Small)"),
+    (77, 0, "Embryo"),
+    (78, 0, "Mom's Heart"),
+    (78, 1, "It Lives"),
+    (78, 10, "Mom's Heart (Mausoleum)"),
+    (79, 0, "Gemini"),
+    (79, 1, "Steven"),
+    (79, 2, "The Blighted Ovum"),
+    (79, 10, "Gemini (Baby)"),
+    (79, 11, "Steven (Baby)"),
+    (79, 12, "The Blighted Ovum (Baby)"),
+    (80, 0, "Moter"),
+    (81, 0, "The Fallen"),
+    (81, 1, "Krampus"),
+    (82, 0, "Headless Horseman"),
+    (82, 1, "Headless Horseman (Head)"),
+    (83, 0, "Horseman Head"),
+    (84, 0, "Satan"),
+    (85, 0, "Spider"),
+    (86, 0, "Keeper"),
+    (87, 0, "Gurgle"),
+    (87, 1, "Crackle"),
+    (88, 0, "Walking Boil"),
+    (88, 1, "Walking Gut"),
+    (88, 2, "Walking Sack"),
+    (89, 0, "Buttlicker"),
+    (90, 0, "Hanger"),
+    (91, 0, "Swarmer"),
+    (92, 0, "Heart"),
+    (93, 0, "Mask"),
+    (93, 1, "Mask of Infamy"),
+    (94, 0, "Big Spider"),
+    (96, 0, "Eternal Fly"),
+    (97, 0, "Mask of Infamy"),
+    (98, 0, "Heart of Infamy"),
+    (99, 0, "Gurdy Jr."),
+    (100, 0, "Widow"),
+    (100, 1, "The Wretched"),
+    (101, 0, "Daddy Long Legs"),
+    (101, 1, "Triachnid"),
+    (102, 0, "Isaac"),
+    (102, 1, "???"),
+    (201, 0, "Stone Eye"),
+    (202, 0, "Constant Stone Shooter"),
+    (203, 0, "Brimstone Head"),
+    (204, 0, "Mobile Host"),
+    (205, 0, "Nest"),
+    (206, 0, "Baby Long Legs"),
+    (206, 1, "Small Baby Long Legs"),
+    (207, 0, "Crazy Long Legs"),
+    (207, 1, "Small Crazy Long Legs"),
+    (208, 0, "Fatty"),
+    (208, 1, "Pale Fatty"),
+    (208, 2, "Flaming Fatty"),
+    (209, 0, "Fat Sack"),
+    (210, 0, "Blubber"),
+    (211, 0, "Half Sack"),
+    (212, 0, "Death's Head"),
+    (212, 1, "Dank Death's Head"),
+    (212, 2, "Cursed Death's Head"),
+    (212, 3, "Brimstone Death's Head"),
+    (212, 4, "Red Skull"),
+    (213, 0, "Mom's Hand"),
+    (214, 0, "Level 2 Fly"),
+    (215, 0, "Level 2 Spider"),
+    (216, 0, "Swinger"),
+    (217, 0, "Dip"),
+    (217, 1, "Corn"),
+    (217, 2, "Brownie Corn"),
+    (217, 3, "Big Corn"),
+    (218, 0, "Wall Hugger"),
+    (219, 0, "Wizoob"),
+    (220, 0, "Squirt"),
+    (220, 1, "Dank Squirt"),
+    (221, 0, "Cod Worm"),
+    (222, 0, "Ring of Flies"),
+    (223, 0, "Dinga"),
+    (224, 0, "Oob"),
+    (225, 0, "Black Maw"),
+    (226, 0, "Skinny"),
+    (226, 1, "Rotty"),
+    (226, 2, "Crispy"),
+    (227, 0, "Bony"),
+    (227, 1, "Holy Bony"),
+    (228, 0, "Homunculus"),
+    (229, 0, "Tumor"),
+    (229, 1, "Planetoid"),
+    (230, 0, "Camillo Jr."),
+    (231, 0, "Nerve Ending"),
+    (231, 1, "Nerve Ending 2"),
+    (232, 0, "Skinball"),
+    (233, 0, "Mom's Head"),
+    (234, 0, "One Tooth"),
+    (235, 0, "Gaping Maw"),
+    (236, 0, "Broken Gaping Maw"),
+    (237, 0, "Gurgling"),
+    (237, 2, "Splasher"),
+    (238, 0, "Splasher"),
+    (239, 0, "Grub"),
+    (240, 0, "Wall Creep"),
+    (240, 1, "Soy Creep"),
+    (240, 3, "Tainted Soy Creep"),
+    (241, 0, "Rage Creep"),
+    (242, 0, "Blind Creep"),
+    (243, 0, "Conjoined Spitty"),
+    (244, 0, "Round Worm"),
+    (244, 1, "Tube Worm"),
+    (244, 2, "Tainted Round Worm"),
+    (244, 3, "Tainted Tube Worm"),
+    (245, 0, "Poop"),
+    (246, 0, "Ragling"),
+    (247, 0, "Flesh Mobile Host"),
+    (248, 0, "Psy Horf"),
+    (249, 0, "Full Fly"),
+    (250, 0, "Ticking Spider"),
+    (251, 0, "Begotten"),
+    (252, 0, "Nulls"),
+    (253, 0, "Psy Tumor"),
+    (254, 0, "Floating Knight"),
+    (255, 0, "Night Crawler"),
+    (256, 0, "Dart Fly"),
+    (257, 0, "Conjoined Fatty"),
+    (257, 1, "Blue Conjoined Fatty"),
+    (258, 0, "Fat Bat"),
+    (259, 0, "Imp"),
+    (260, 0, "The Haunt"),
+    (260, 10, "Lil' Haunt"),
+    (261, 0, "Dingle"),
+    (261, 1, "Dangle"),
+    (262, 0, "Mega Maw"),
+    (263, 0, "The Gate"),
+    (264, 0, "Mega Fatty"),
+    (265, 0, "The Cage"),
+    (266, 0, "Mama Gurdy"),
+    (267, 0, "Dark One"),
+    (268, 0, "The Adversary"),
+    (269, 0, "Polycephalus"),
+    (269, 1, "The Pile"),
+    (270, 0, "Mr. Fred"),
+    (271, 0, "Uriel"),
+    (271, 1, "Fallen Uriel"),
+    (272, 0, "Gabriel"),
+    (272, 1, "Fallen Gabriel"),
+    (273, 0, "The Lamb"),
+    (274, 0, "Mega Satan"),
+    (275, 0, "Mega Satan 2"),
+    (276, 0, "Roundy"),
+    (277, 0, "Black Bony"),
+    (278, 0, "Black Globin"),
+    (279, 0, "Black Globin's Head"),
+    (280, 0, "Black Globin's Body"),
+    (281, 0, "Swarm"),
+    (282, 0, "Mega Clotty"),
+    (283, 0, "Bone Knight"),
+    (284, 0, "Cyclopia"),
+    (285, 0, "Red Ghost"),
+    (286, 0, "Flesh Death's Head"),
+    (287, 0, "Mom's Dead Hand"),
+    (288, 0, "Dukie"),
+    (289, 0, "Ulcer"),
+    (290, 0, "Meatball"),
+    (291, 0, "Pitfall"),
+    (292, 0, "Movable TNT"),
+    (293, 0, "Ultra Coin"),
+    (294, 0, "Ultra Door"),
+    (295, 0, "Corn Mine"),
+    (296, 0, "Hush Fly"),
+    (297, 0, "Hush Gaper"),
+    (298, 0, "Hush Boil"),
+    (299, 0, "Greed Gaper"),
+    (300, 0, "Mushroom"),
+    (301, 0, "Poison Mind"),
+    (302, 0, "Stoney"),
+    (303, 0, "Blister"),
+    (304, 0, "The Thing"),
+    (305, 0, "Ministro"),
+    (306, 0, "Portal"),
+    (306, 1, "Lil' Portal"),
+    (307, 0, "Tar Boy"),
+    (308, 0, "Fistuloid"),
+    (309, 0, "Gush"),
+    (310, 0, "Leper"),
+    (311, 0, "Mr. Mine"),
+    (401, 0, "The Stain"),
+    (402, 0, "Brownie"),
+    (403, 0, "The Forsaken"),
+    (404, 0, "Little Horn"),
+    (405, 0, "Rag Man"),
+    (406, 0, "Ultra Greed"),
+    (406, 1, "Ultra Greedier"),
+    (407, 0, "Hush"),
+    (409, 0, "Rag Mega"),
+    (410, 0, "Sisters Vis"),
+    (411, 0, "Big Horn"),
+    (412, 0, "Delirium"),
+    (413, 0, "The Matriarch"),
+    (802, 0, "Blood Puppy"),
+    (804, 0, "Quake Grimace"),
+    (805, 0, "Bishop"),
+    (806, 0, "Bubbles"),
+    (807, 0, "Wraith"),
+    (808, 0, "Will o' the Wisp"),
+    (809, 0, "Bomb Grimace"),
+    (810, 0, "Small Leech"),
+    (811, 0, "Deep Gaper"),
+    (812, 0, "Sub Horf"),
+    (812, 1, "Tainted Sub Horf"),
+    (813, 0, "Blurb"),
+    (814, 0, "Strider"),
+    (815, 0, "Fissure"),
+    (816, 0, "Polty"),
+    (816, 1, "Kineti"),
+    (817, 0, "Prey"),
+    (817, 1, "Mullighoul"),
+    (818, 0, "Rock Spider"),
+    (818, 1, "Tinted Rock Spider"),
+    (818, 2, "Coal Spider"),
+    (819, 0, "Fly Bomb"),
+    (820, 0, "Danny"),
+    (820, 1, "Coal Boy"),
+    (821, 0, "Blaster"),
+    (822, 0, "Bouncer"),
+    (823, 0, "Quakey"),
+    (824, 0, "Gyro"),
+    (824, 1, "Grilled Gyro"),
+    (825, 0, "Fire Worm"),
+    (826, 0, "Hardy"),
+    (827, 0, "Faceless"),
+    (827, 1, "Tainted Faceless"),
+    (828, 0, "Necro"),
+    (829, 0, "Mole"),
+    (829, 1, "Tainted Mole"),
+    (830, 0, "Big Bony"),
+    (831, 0, "Gutted Fatty"),
+    (832, 0, "Exorcist"),
+    (832, 1, "Fanatic"),
+    (833, 0, "Candler"),
+    (834, 0, "Whipper"),
+    (834, 1, "Snapper"),
+    (834, 2, "Flagellant"),
+    (835, 0, "Peeper Fatty"),
+    (836, 0, "Vis Versa"),
+    (837, 0, "Henry"),
+    (838, 0, "Level 2 Will o' the Wisp"),
+    (839, 0, "Pon"),
+    (840, 0, "Revenant"),
+    (841, 0, "Bomb Gagger"),
+    (842, 0, "Level 2 Gaper"),
+    (843, 0, "Twitchy"),
+    (844, 0, "Spikeball"),
+    (845, 0, "Small Maggot"),
+    (846, 0, "Adult Leech"),
+    (847, 0, "Level 2 Charger"),
+    (848, 0, "Gasbag"),
+    (849, 0, "Cohort"),
+    (850, 0, "Floating Host"),
+    (851, 0, "Unborn"),
+    (852, 0, "Pustule"),
+    (853, 0, "Cyst"),
+    (854, 0, "Morningstar"),
+    (855, 0, "Mockulus"),
+    (856, 0, "Evis"),
+    (857, 0, "Dark Esau"),
+    (858, 0, "Mother's Shadow"),
+    (859, 0, "Army Fly"),
+    (860, 0, "Migraine"),
+    (861, 0, "Drip"),
+    (862, 0, "Splurt"),
+    (863, 0, "Cloggy"),
+    (864, 0, "Fly Trap"),
+    (865, 0, "Gas Dwarf"),
+    (866, 0, "Poot Mine"),
+    (867, 0, "Dump"),
+    (868, 0, "Grudge"),
+    (869, 0, "Butt Slicker"),
+    (870, 0, "Bloaty"),
+    (871, 0, "Flesh Maiden"),
+    (872, 0, "Needle"),
+    (873, 0, "Dust"),
+    (874, 0, "Baby Begotten"),
+    (875, 0, "Swarm Spider"),
+    (876, 0, "Cultist"),
+    (876, 1, "Blood Cultist"),
+    (877, 0, "Vis Fatty"),
+    (878, 0, "Dusty Death's Head"),
+    (880, 0, "Shady"),
+    (881, 0, "Clickety Clack"),
+    (882, 0, "Maze Roamer"),
+    (883, 0, "Goat"),
+    (884, 0, "Poofer"),
+    (900, 0, "Reap Creep"),
+    (901, 0, "Lil Blub"),
+    (902, 0, "Rainmaker"),
+    (903, 0, "Visage"),
+    (904, 0, "Siren"),
+    (905, 0, "The Heretic"),
+    (906, 0, "Hornfel"),
+    (907, 0, "Great Gideon"),
+    (908, 0, "Baby Plum"),
+    (909, 0, "The Scourge"),
+    (910, 0, "Chimera"),
+    (911, 0, "Rotgut"),
+    (912, 0, "Mother"),
+    (913, 0, "Min-Min"),
+    (914, 0, "Clog"),
+    (915, 0, "Singe"),
+    (916, 0, "Bumbino"),
+    (917, 0, "Colostomia"),
+    (918, 0, "Turdlet"),
+    (919, 0, "Raglich"),
+    (920, 0, "Horny Boys"),
+    (921, 0, "Clutch"),
+    (950, 0, "Dogma"),
+    (951, 0, "The Beast"),
+    (951, 10, "Ultra Famine"),
+    (951, 20, "Ultra Pestilence"),
+    (951, 30, "Ultra War"),
+    (951, 40, "Ultra Death"),
+];
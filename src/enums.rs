@@ -1,12 +1,16 @@
-use strum_macros::{Display, FromRepr};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, FromRepr};
 
-#[derive(Clone, Copy, Display, FromRepr)]
+#[derive(Clone, Copy, Deserialize, Display, FromRepr, PartialEq, Serialize)]
 pub enum IsaacVersion {
     Rebirth,
     Afterbirth,
+    #[strum(serialize = "Afterbirth+")]
     AfterbirthPlus,
+    #[strum(serialize = "Afterbirth+ (Booster Pack 5)")]
     AfterbirthPlusBP5,
     Repentance,
+    #[strum(serialize = "Repentance+")]
     RepentancePlus,
 }
 
@@ -16,5 +20,117 @@ pub enum Activity {
     Backup,
     Delete,
     ChangeSteamCloud,
-    ManuallyInstall,
+    Import,
+    Statistics,
+    Collection,
+    Challenges,
+    Bestiary,
+    Progress,
+    Diff,
+    Convert,
+    Reset,
+    ManageSlots,
+    Undo,
+    SetUpPaths,
+    ToggleDryRun,
+    ChangeVersion,
+    Quit,
+}
+
+/// The operations that are recorded in the history file.
+#[derive(Clone, Copy, Deserialize, Display, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    Install,
+    Import,
+    Backup,
+    /// Removing old backups.
+    Prune,
+    Delete,
+    Reset,
+    Edit,
+    Convert,
+    Copy,
+    Move,
+    Swap,
+    #[strum(serialize = "Change SteamCloud")]
+    ChangeSteamCloud,
+    Undo,
+}
+
+#[derive(Clone, Copy, FromRepr, PartialEq)]
+pub enum CollectionAction {
+    Nothing,
+    CollectAll,
+    UncollectAll,
+    Collect,
+    Uncollect,
+}
+
+#[derive(Clone, Copy, Display, FromRepr, PartialEq)]
+pub enum SlotAction {
+    #[strum(serialize = "copy")]
+    Copy,
+    #[strum(serialize = "move")]
+    Move,
+    #[strum(serialize = "swap")]
+    Swap,
+}
+
+#[derive(Clone, Copy, FromRepr, PartialEq)]
+pub enum ChallengeAction {
+    Nothing,
+    CompleteAll,
+    ResetAll,
+    Complete,
+    Reset,
+}
+
+/// The statistics that the game tracks in the "counters" section of the save file.
+#[derive(Clone, Copy, Display, EnumIter, PartialEq)]
+pub enum Counter {
+    #[strum(serialize = "Mom kills")]
+    MomKills,
+    #[strum(serialize = "Rocks destroyed")]
+    RocksDestroyed,
+    #[strum(serialize = "Tinted rocks destroyed")]
+    TintedRocksDestroyed,
+    #[strum(serialize = "Poops destroyed")]
+    PoopsDestroyed,
+    #[strum(serialize = "Pills eaten")]
+    PillsEaten,
+    #[strum(serialize = "Wins")]
+    Wins,
+    #[strum(serialize = "Deaths")]
+    Deaths,
+    #[strum(serialize = "Shopkeepers killed")]
+    ShopkeepersKilled,
+    #[strum(serialize = "Eden tokens")]
+    EdenTokens,
+    #[strum(serialize = "Donation machine")]
+    DonationMachine,
+    #[strum(serialize = "Win streak")]
+    WinStreak,
+    #[strum(serialize = "Greed donation machine")]
+    GreedDonationMachine,
+}
+
+/// The lists in the "bestiary" section of the save file, with their in-game list types.
+#[derive(Clone, Copy, Display, EnumIter, FromRepr, PartialEq)]
+#[repr(u32)]
+pub enum BestiaryStat {
+    Encounters = 1,
+    Kills = 2,
+    Hits = 3,
+    Deaths = 4,
+}
+
+#[derive(Clone, Copy, FromRepr, PartialEq)]
+pub enum BestiaryView {
+    SortByEntity,
+    SortByEncounters,
+    SortByKills,
+    SortByHits,
+    SortByDeaths,
+    ExportJson,
 }
@@ -0,0 +1,68 @@
+use anyhow::Error;
+use std::io;
+use thiserror::Error;
+
+/// The kinds of failures that scripts need to tell apart. Each one has its own exit code, which must
+/// not change between releases.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    Other = 1,
+    GameRunning = 2,
+    SteamNotLoggedIn = 3,
+    SaveDirNotFound = 4,
+    OptionsIni = 5,
+    SlotEmpty = 6,
+    PermissionDenied = 8,
+    CorruptSave = 9,
+}
+
+impl ErrorKind {
+    pub fn of(err: &Error) -> Self {
+        if let Some(installer_error) = err.downcast_ref::<InstallerError>() {
+            return installer_error.kind;
+        }
+
+        let permission_denied = err.chain().any(|cause| {
+            cause
+                .downcast_ref::<io::Error>()
+                .is_some_and(|io_error| io_error.kind() == io::ErrorKind::PermissionDenied)
+        });
+        match permission_denied {
+            true => Self::PermissionDenied,
+            false => Self::Other,
+        }
+    }
+
+    pub fn exit_code(self) -> i32 {
+        self as i32
+    }
+
+    pub fn hint(self) -> Option<&'static str> {
+        match self {
+            Self::Other => None,
+            Self::GameRunning => Some("Close the game before you run this installer."),
+            Self::SteamNotLoggedIn => Some("Please make sure that Steam is open and that you are logged in. (Or give your Steam user ID with the \"--steam-user\" flag.)"),
+            Self::SaveDirNotFound => Some("Do you have the selected version of the game installed? If you do, try opening the game, closing the game, and then retrying (so that the \"log.txt\" file is created). If your save files are in a custom location, you can set up the paths to them from the menu."),
+            Self::OptionsIni => Some("Try opening the game, closing the game, and then retrying (so that the game writes a new \"options.ini\" file)."),
+            Self::SlotEmpty => Some("Choose a slot that has a save file in it."),
+            Self::PermissionDenied => Some("You can try running this program again as an administrator:\nhttps://www.digitalcitizen.life/run-as-admin/"),
+            Self::CorruptSave => Some("Restore a backup of the save file, or install a new save file to the slot."),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("{message}")]
+pub struct InstallerError {
+    kind: ErrorKind,
+    message: String,
+}
+
+impl InstallerError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
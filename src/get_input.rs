@@ -1,20 +1,106 @@
 use crate::{
     constants::STEAM_CLOUD_NAME,
-    enums::{Activity, IsaacVersion},
+    dry_run::is_dry_run,
+    enums::{
+        Activity, BestiaryView, ChallengeAction, CollectionAction, Counter, IsaacVersion,
+        SlotAction,
+    },
+    print_error,
     save_data_path::toggle_steam_cloud_enabled,
+    templates::SaveTemplate,
 };
 use anyhow::{bail, Context, Result};
-use camino::Utf8Path;
+use camino::{Utf8Path, Utf8PathBuf};
 use colored::*;
-use std::ops::RangeInclusive;
-use text_io::try_read;
+use std::{
+    collections::VecDeque,
+    fmt::Display,
+    fs::read_to_string,
+    ops::RangeInclusive,
+    sync::{Mutex, PoisonError},
+};
 
 const SELECTION_ERROR_MSG: &str = "That is not a valid selection.";
 const INPUT_NUMBER_EXPLANATION_MSG: &str = "[Type the number and press enter.]";
 const INPUT_BOOL_EXPLANATION_MSG: &str = "[Type y or n and press enter.]";
+const INPUT_LINE_EXPLANATION_MSG: &str = "[Type the text and press enter.]";
+const INPUT_SLOTS_EXPLANATION_MSG: &str =
+    "[Type one or more numbers separated by commas (or \"all\") and press enter.]";
+
+/// Where the answers to the prompts come from.
+pub trait Input: Send {
+    /// Returns the next line of input without the line ending.
+    fn read_line(&mut self) -> Result<String>;
+}
+
+/// Reads the answers that the user types in the terminal.
+pub struct TerminalInput;
+
+impl Input for TerminalInput {
+    fn read_line(&mut self) -> Result<String> {
+        let mut line = String::new();
+        let num_bytes = std::io::stdin()
+            .read_line(&mut line)
+            .context("Failed to read from the terminal.")?;
+        if num_bytes == 0 {
+            bail!("There is no more input to read.");
+        }
+
+        Ok(line.trim_end_matches(['\r', '\n']).to_string())
+    }
+}
+
+/// Replays a list of answers, one for each prompt.
+pub struct ScriptedInput {
+    answers: VecDeque<String>,
+}
+
+impl ScriptedInput {
+    pub fn new(answers: Vec<String>) -> Self {
+        Self {
+            answers: answers.into(),
+        }
+    }
+
+    /// Each line of the file is one answer.
+    pub fn from_file(path: &Utf8Path) -> Result<Self> {
+        let answers = read_to_string(path).context(format!(
+            "Failed to read the file:\n{}",
+            path.to_string().green(),
+        ))?;
+
+        Ok(Self::new(answers.lines().map(str::to_string).collect()))
+    }
+}
+
+impl Input for ScriptedInput {
+    fn read_line(&mut self) -> Result<String> {
+        let answer = self
+            .answers
+            .pop_front()
+            .context("There are no more scripted answers.")?;
+
+        // Show the answer so that the output looks the same as if it was typed.
+        println!("{}", answer);
+
+        Ok(answer)
+    }
+}
+
+/// There is only one terminal, so every thread reads from the same input. When no input was set, the
+/// answers are read from the terminal.
+static INPUT: Mutex<Option<Box<dyn Input>>> = Mutex::new(None);
+
+pub fn set_input(input: Box<dyn Input>) {
+    *INPUT.lock().unwrap_or_else(PoisonError::into_inner) = Some(input);
+}
 
 fn get_user_input_string() -> Result<String> {
-    let input: String = try_read!("{}").context(SELECTION_ERROR_MSG)?;
+    let input = INPUT
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get_or_insert_with(|| Box::new(TerminalInput))
+        .read_line()?;
     println!();
 
     let trimmed_input = input.trim().to_string();
@@ -34,11 +120,33 @@ fn get_user_input_y_n() -> Result<bool> {
 }
 
 fn get_user_input_number() -> Result<usize> {
+    get_user_input_number_or_default(None)
+}
+
+/// If there is a default, it is used when the input is empty.
+fn get_user_input_number_or_default(default: Option<usize>) -> Result<usize> {
     let input = get_user_input_string()?;
-    let number: usize = input
+    if let (true, Some(default)) = (input.is_empty(), default) {
+        return Ok(default);
+    }
+
+    parse_number(&input)
+}
+
+fn parse_number(input: &str) -> Result<usize> {
+    input
         .parse()
-        .context(format!("Failed to convert \"{}\" to a number.", input))?;
-    Ok(number)
+        .context(format!("Failed to convert \"{}\" to a number.", input))
+}
+
+fn print_number_explanation(default: Option<impl Display>) {
+    match default {
+        Some(default) => println!(
+            "[Type the number and press enter, or just press enter for {}.]",
+            default,
+        ),
+        None => println!("{}", INPUT_NUMBER_EXPLANATION_MSG),
+    }
 }
 
 pub fn check_pirate() -> Result<()> {
@@ -54,18 +162,31 @@ pub fn check_pirate() -> Result<()> {
     }
 }
 
-pub fn prompt_for_isaac_version() -> Result<IsaacVersion> {
+pub fn prompt_for_isaac_version(last_isaac_version: Option<IsaacVersion>) -> Result<IsaacVersion> {
     println!("Which game do you want to manage the save files for?");
+    get_user_input_isaac_version(last_isaac_version)
+}
+
+pub fn prompt_for_conversion_isaac_version(isaac_version: IsaacVersion) -> Result<IsaacVersion> {
+    println!(
+        "Which game do you want to convert the {} save file to?",
+        isaac_version,
+    );
+    get_user_input_isaac_version(None)
+}
+
+fn get_user_input_isaac_version(default: Option<IsaacVersion>) -> Result<IsaacVersion> {
     println!("1) The Binding of Isaac: Rebirth");
     println!("2) The Binding of Isaac: Afterbirth");
     println!("3) The Binding of Isaac: Afterbirth+ (Vanilla through Booster Pack 4)");
     println!("4) The Binding of Isaac: Afterbirth+ (Booster Pack 5)");
     println!("5) The Binding of Isaac: Repentance");
     println!("6) The Binding of Isaac: Repentance+");
-    println!("{}", INPUT_NUMBER_EXPLANATION_MSG);
+    print_number_explanation(default);
 
-    let input = get_user_input_number()?;
-    let enum_value = input - 1; // e.g. 1 corresponds to element 0
+    let default_input = default.map(|isaac_version| isaac_version as usize + 1);
+    let input = get_user_input_number_or_default(default_input)?;
+    let enum_value = input.checked_sub(1).context(SELECTION_ERROR_MSG)?; // e.g. 1 corresponds to element 0
     let isaac_version = IsaacVersion::from_repr(enum_value).context(SELECTION_ERROR_MSG)?;
 
     Ok(isaac_version)
@@ -117,36 +238,139 @@ pub fn confirm_toggle_steam_cloud(steam_cloud_enabled: bool) -> Result<bool> {
     get_user_input_y_n()
 }
 
+/// An invalid selection does not end the program, since the user is asked again.
 pub fn prompt_for_activity() -> Result<Activity> {
+    loop {
+        print_activities();
+
+        let input = get_user_input_string()?;
+        match parse_activity(&input) {
+            Ok(activity) => return Ok(activity),
+            Err(err) => print_error(&err),
+        }
+    }
+}
+
+fn print_activities() {
     println!("What do you want to do?");
     println!("1) Install a new fully-unlocked file.");
     println!("2) Backup an existing save file.");
     println!("3) Delete an existing save file.");
     println!("4) Change your \"SteamCloud\" setting in the \"options.ini\" file.");
-    println!("5) Manually install a save file without using this installer.");
+    println!("5) Install a save file from your computer.");
+    println!("6) View or edit the statistics of an existing save file.");
+    println!("7) View or edit the collection page of an existing save file.");
+    println!("8) View or edit the challenges of an existing save file.");
+    println!("9) View the bestiary of an existing save file. (Repentance only)");
+    println!("10) See what is left to unlock in an existing save file.");
+    println!("11) Compare two save files.");
+    println!("12) Convert an existing save file to another version of the game.");
+    println!("13) Reset a save file to a brand-new profile.");
+    println!("14) Copy, move, or swap save files between slots.");
+    println!("15) Undo the last change to a save file.");
+    println!("16) Set up the paths to your save files.");
+    println!(
+        "17) Turn dry-run mode {}. (Show what would be changed without changing anything.)",
+        match is_dry_run() {
+            true => "off",
+            false => "on",
+        },
+    );
+    println!("18) Manage the save files for a different game.");
+    println!("19) Quit.");
     println!("{}", INPUT_NUMBER_EXPLANATION_MSG);
+}
 
-    let input = get_user_input_number()?;
-    let enum_value = input - 1; // e.g. 1 corresponds to element 0
+fn parse_activity(input: &str) -> Result<Activity> {
+    let input = parse_number(input)?;
+    let enum_value = input.checked_sub(1).context(SELECTION_ERROR_MSG)?; // e.g. 1 corresponds to element 0
     let activity = Activity::from_repr(enum_value).context(SELECTION_ERROR_MSG)?;
 
     Ok(activity)
 }
 
-pub fn prompt_for_save_file_slot(activity: Activity) -> Result<usize> {
-    let verb = match activity {
+pub fn prompt_for_save_file_slot(
+    activity: Activity,
+    last_save_file_slot: Option<usize>,
+) -> Result<usize> {
+    println!(
+        "Which save file do you want to {}?",
+        get_activity_verb(activity)
+    );
+    get_user_input_save_file_slot(last_save_file_slot)
+}
+
+/// Returns the selected slots in ascending order.
+pub fn prompt_for_save_file_slots(
+    activity: Activity,
+    last_save_file_slot: Option<usize>,
+) -> Result<Vec<usize>> {
+    println!(
+        "Which save files do you want to {}?",
+        get_activity_verb(activity)
+    );
+    println!("1) Save slot 1");
+    println!("2) Save slot 2");
+    println!("3) Save slot 3");
+    println!("{}", INPUT_SLOTS_EXPLANATION_MSG);
+    if let Some(last_save_file_slot) = last_save_file_slot {
+        println!("[Or just press enter for slot {}.]", last_save_file_slot);
+    }
+
+    let input = get_user_input_string()?;
+    if input.to_lowercase() == "all" {
+        return Ok(vec![1, 2, 3]);
+    }
+    if let (true, Some(last_save_file_slot)) = (input.is_empty(), last_save_file_slot) {
+        return Ok(vec![last_save_file_slot]);
+    }
+
+    let mut save_file_slots = Vec::new();
+    for slot in input
+        .split(|character: char| character == ',' || character.is_whitespace())
+        .filter(|slot| !slot.is_empty())
+    {
+        let save_file_slot: usize = slot
+            .parse()
+            .context(format!("Failed to convert \"{}\" to a number.", slot))?;
+        if !RangeInclusive::new(1, 3).contains(&save_file_slot) {
+            bail!(SELECTION_ERROR_MSG);
+        }
+        save_file_slots.push(save_file_slot);
+    }
+    if save_file_slots.is_empty() {
+        bail!(SELECTION_ERROR_MSG);
+    }
+    save_file_slots.sort_unstable();
+    save_file_slots.dedup();
+
+    Ok(save_file_slots)
+}
+
+fn get_activity_verb(activity: Activity) -> &'static str {
+    match activity {
         Activity::Backup => "backup",
+        Activity::Delete => "delete",
         Activity::Install => "install the fully-unlocked save file to",
+        Activity::Import => "install the save file to",
+        Activity::Statistics => "view the statistics of",
+        Activity::Collection => "view the collection page of",
+        Activity::Challenges => "view the challenges of",
+        Activity::Bestiary => "view the bestiary of",
+        Activity::Progress => "check the progress of",
+        Activity::Convert => "convert",
+        Activity::Reset => "reset to a brand-new profile",
         _ => "touch",
-    };
+    }
+}
 
-    println!("Which save file do you want to {}?", verb);
+fn get_user_input_save_file_slot(default: Option<usize>) -> Result<usize> {
     println!("1) Save slot 1");
     println!("2) Save slot 2");
     println!("3) Save slot 3");
-    println!("{}", INPUT_NUMBER_EXPLANATION_MSG);
+    print_number_explanation(default.map(|slot| format!("slot {}", slot)));
 
-    let input = get_user_input_number()?;
+    let input = get_user_input_number_or_default(default)?;
     if RangeInclusive::new(1, 3).contains(&input) {
         return Ok(input);
     }
@@ -154,7 +378,315 @@ pub fn prompt_for_save_file_slot(activity: Activity) -> Result<usize> {
     bail!(SELECTION_ERROR_MSG)
 }
 
+pub fn prompt_for_slot_action() -> Result<SlotAction> {
+    println!("What do you want to do?");
+    println!("1) Copy a save file to another slot.");
+    println!("2) Move a save file to another slot.");
+    println!("3) Swap the save files in two slots.");
+    println!("{}", INPUT_NUMBER_EXPLANATION_MSG);
+
+    let input = get_user_input_number()?;
+    let enum_value = input.checked_sub(1).context(SELECTION_ERROR_MSG)?; // e.g. 1 corresponds to element 0
+    let slot_action = SlotAction::from_repr(enum_value).context(SELECTION_ERROR_MSG)?;
+
+    Ok(slot_action)
+}
+
+pub fn prompt_for_source_save_file_slot(slot_action: SlotAction) -> Result<usize> {
+    println!("Which save file do you want to {}?", slot_action);
+    get_user_input_save_file_slot(None)
+}
+
+pub fn prompt_for_destination_save_file_slot(slot_action: SlotAction) -> Result<usize> {
+    let preposition = match slot_action {
+        SlotAction::Copy | SlotAction::Move => "to",
+        SlotAction::Swap => "with",
+    };
+    println!(
+        "Which slot do you want to {} it {}?",
+        slot_action, preposition
+    );
+    get_user_input_save_file_slot(None)
+}
+
+pub fn confirm_edit_counter() -> Result<bool> {
+    println!("Do you want to edit one of these statistics?");
+    println!("{}", INPUT_BOOL_EXPLANATION_MSG);
+
+    get_user_input_y_n()
+}
+
+pub fn prompt_for_counter(counters: &[Counter]) -> Result<Counter> {
+    println!("Which statistic do you want to edit?");
+    for (i, counter) in counters.iter().enumerate() {
+        println!("{}) {}", i + 1, counter);
+    }
+    println!("{}", INPUT_NUMBER_EXPLANATION_MSG);
+
+    let input = get_user_input_number()?;
+    let index = input.checked_sub(1).context(SELECTION_ERROR_MSG)?;
+    let counter = counters.get(index).context(SELECTION_ERROR_MSG)?;
+
+    Ok(*counter)
+}
+
+pub fn prompt_for_counter_value(counter: Counter) -> Result<u32> {
+    println!("What do you want to set \"{}\" to?", counter);
+    println!("{}", INPUT_NUMBER_EXPLANATION_MSG);
+
+    // The game stores the counters as signed 32-bit integers.
+    let input = get_user_input_number()?;
+    if input > i32::MAX as usize {
+        bail!(
+            "The value for \"{}\" cannot be larger than {}.",
+            counter,
+            i32::MAX
+        );
+    }
+
+    Ok(input as u32)
+}
+
+pub fn prompt_for_collection_action() -> Result<CollectionAction> {
+    println!("What do you want to do with the collection page?");
+    println!("1) Nothing.");
+    println!("2) Mark every item as collected.");
+    println!("3) Mark every item as uncollected.");
+    println!("4) Mark specific items as collected.");
+    println!("5) Mark specific items as uncollected.");
+    println!("{}", INPUT_NUMBER_EXPLANATION_MSG);
+
+    let input = get_user_input_number()?;
+    let enum_value = input.checked_sub(1).context(SELECTION_ERROR_MSG)?; // e.g. 1 corresponds to element 0
+    let collection_action = CollectionAction::from_repr(enum_value).context(SELECTION_ERROR_MSG)?;
+
+    Ok(collection_action)
+}
+
+pub fn prompt_for_collectible_names() -> Result<Vec<String>> {
+    println!(
+        "Which items? Use the item names or IDs, separated by commas. (e.g. \"The Sad Onion, 2\")"
+    );
+    println!("{}", INPUT_LINE_EXPLANATION_MSG);
+
+    let input = get_user_input_string()?;
+    let names = input
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    Ok(names)
+}
+
+pub fn prompt_for_challenge_action() -> Result<ChallengeAction> {
+    println!("What do you want to do with the challenges?");
+    println!("1) Nothing.");
+    println!("2) Complete every challenge.");
+    println!("3) Reset every challenge.");
+    println!("4) Complete specific challenges.");
+    println!("5) Reset specific challenges.");
+    println!("{}", INPUT_NUMBER_EXPLANATION_MSG);
+
+    let input = get_user_input_number()?;
+    let enum_value = input.checked_sub(1).context(SELECTION_ERROR_MSG)?; // e.g. 1 corresponds to element 0
+    let challenge_action = ChallengeAction::from_repr(enum_value).context(SELECTION_ERROR_MSG)?;
+
+    Ok(challenge_action)
+}
+
+pub fn prompt_for_challenge_names() -> Result<Vec<String>> {
+    println!("Which challenges? Use the challenge names or numbers, separated by commas. (e.g. \"Pitch Black, 2\")");
+    println!("{}", INPUT_LINE_EXPLANATION_MSG);
+
+    let input = get_user_input_string()?;
+    let names = input
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect();
+
+    Ok(names)
+}
+
+pub fn prompt_for_bestiary_view() -> Result<BestiaryView> {
+    println!("How do you want to view the bestiary?");
+    println!("1) Print a table sorted by entity.");
+    println!("2) Print a table sorted by encounters.");
+    println!("3) Print a table sorted by kills.");
+    println!("4) Print a table sorted by hits.");
+    println!("5) Print a table sorted by deaths.");
+    println!("6) Export it to a JSON file.");
+    println!("{}", INPUT_NUMBER_EXPLANATION_MSG);
+
+    let input = get_user_input_number()?;
+    let enum_value = input.checked_sub(1).context(SELECTION_ERROR_MSG)?; // e.g. 1 corresponds to element 0
+    let bestiary_view = BestiaryView::from_repr(enum_value).context(SELECTION_ERROR_MSG)?;
+
+    Ok(bestiary_view)
+}
+
+pub fn prompt_for_save_file_to_compare(ordinal: &str) -> Result<String> {
+    println!(
+        "What is the {} save file that you want to compare?",
+        ordinal
+    );
+    println!("- Type a slot number to use that slot. (e.g. \"1\")");
+    println!("- Type \"backup\" and a slot number to use the newest backup of that slot. (e.g. \"backup 1\")");
+    println!("- Type the path to any other save file.");
+    println!("{}", INPUT_LINE_EXPLANATION_MSG);
+
+    let input = get_user_input_string()?;
+    let input = input.trim_matches('"').to_string();
+    if input.is_empty() {
+        bail!(SELECTION_ERROR_MSG);
+    }
+
+    Ok(input)
+}
+
+pub fn confirm_convert_in_place(save_file_path: &Utf8Path) -> Result<bool> {
+    println!("Both versions of the game use the same save file, so the converted save file will overwrite the original one:");
+    println!("{}", save_file_path.to_string().green());
+    println!("Do you want to continue?");
+    println!("{}", INPUT_BOOL_EXPLANATION_MSG);
+
+    get_user_input_y_n()
+}
+
+pub fn confirm_reset(save_file_path: &Utf8Path) -> Result<bool> {
+    println!("This will permanently erase all of the progress in the following save file:");
+    println!("{}", save_file_path.to_string().green());
+    println!("Do you want to continue?");
+    println!("{}", INPUT_BOOL_EXPLANATION_MSG);
+
+    get_user_input_y_n()
+}
+
+pub fn confirm_undo(history_entry_lines: &[String]) -> Result<bool> {
+    println!("This will undo the following change:");
+    for line in history_entry_lines {
+        println!("{}", line);
+    }
+    println!("Do you want to continue?");
+    println!("{}", INPUT_BOOL_EXPLANATION_MSG);
+
+    get_user_input_y_n()
+}
+
+pub fn prompt_for_import_path() -> Result<Utf8PathBuf> {
+    println!("What is the path to the save file that you want to install? (You can also drag and drop the file into this window.)");
+    println!("{}", INPUT_LINE_EXPLANATION_MSG);
+
+    // Dragging and dropping a file puts quotes around the path.
+    let input = get_user_input_string()?;
+    let path = input.trim_matches('"').trim();
+    if path.is_empty() {
+        bail!(SELECTION_ERROR_MSG);
+    }
+
+    Ok(Utf8PathBuf::from(path))
+}
+
+pub fn confirm_overwrite_save_file(save_file_path: &Utf8Path) -> Result<bool> {
+    println!("The following save file already exists and will be overwritten:");
+    println!("{}", save_file_path.to_string().green());
+    println!("Do you want to continue?");
+    println!("{}", INPUT_BOOL_EXPLANATION_MSG);
+
+    get_user_input_y_n()
+}
+
+/// Returns `None` for the built-in fully-unlocked save file.
+pub fn prompt_for_save_template(save_templates: &[SaveTemplate]) -> Result<Option<&SaveTemplate>> {
+    if save_templates.is_empty() {
+        return Ok(None);
+    }
+
+    println!("Which save file do you want to install?");
+    println!("1) The built-in fully-unlocked save file.");
+    for (i, save_template) in save_templates.iter().enumerate() {
+        match save_template.description.is_empty() {
+            true => println!("{}) {}", i + 2, save_template.name),
+            false => println!(
+                "{}) {} - {}",
+                i + 2,
+                save_template.name,
+                save_template.description,
+            ),
+        }
+    }
+    println!("{}", INPUT_NUMBER_EXPLANATION_MSG);
+
+    let input = get_user_input_number()?;
+    match input {
+        1 => Ok(None),
+        _ => {
+            let index = input.checked_sub(2).context(SELECTION_ERROR_MSG)?;
+            let save_template = save_templates.get(index).context(SELECTION_ERROR_MSG)?;
+            Ok(Some(save_template))
+        }
+    }
+}
+
 pub fn prompt_for_user_to_hit_enter() -> Result<()> {
     get_user_input_string()?;
     Ok(())
 }
+
+pub fn confirm_set_up_paths() -> Result<bool> {
+    println!("Do you want to type the paths to your save files yourself? (They will be remembered for the next time.)");
+    println!("{}", INPUT_BOOL_EXPLANATION_MSG);
+
+    get_user_input_y_n()
+}
+
+/// Returns `None` to find the directory automatically.
+pub fn prompt_for_documents_dir(current: Option<&Utf8Path>) -> Result<Option<Utf8PathBuf>> {
+    println!("What is the path to your \"Documents\" directory? (This is the directory that contains the \"My Games\" directory.)");
+    prompt_for_setting(current.map(Utf8Path::as_str))
+        .map(|documents_dir| documents_dir.map(Utf8PathBuf::from))
+}
+
+/// Returns `None` to find the directory automatically.
+pub fn prompt_for_steam_dir(current: Option<&Utf8Path>) -> Result<Option<Utf8PathBuf>> {
+    println!("What is the path to the directory that Steam is installed in? (e.g. \"C:\\Program Files (x86)\\Steam\")");
+    prompt_for_setting(current.map(Utf8Path::as_str))
+        .map(|steam_dir| steam_dir.map(Utf8PathBuf::from))
+}
+
+/// Returns `None` to find the user automatically.
+pub fn prompt_for_steam_user_id(current: Option<u32>) -> Result<Option<u32>> {
+    println!("What is your Steam user ID? (This is the name of your directory in the \"userdata\" directory of Steam.)");
+    let current = current.map(|steam_user_id| steam_user_id.to_string());
+    match prompt_for_setting(current.as_deref())? {
+        Some(input) => {
+            let steam_user_id = input
+                .parse()
+                .context(format!("Failed to convert \"{}\" to a number.", input))?;
+            Ok(Some(steam_user_id))
+        }
+        None => Ok(None),
+    }
+}
+
+fn prompt_for_setting(current: Option<&str>) -> Result<Option<String>> {
+    match current {
+        Some(current) => {
+            println!("Currently: {}", current.green());
+            println!("[Type the new value and press enter, just press enter to keep it, or type \"auto\" to find it automatically.]");
+        }
+        None => println!(
+            "[Type the value and press enter, or just press enter to find it automatically.]"
+        ),
+    }
+
+    // Dragging and dropping a directory puts quotes around the path.
+    let input = get_user_input_string()?;
+    let input = input.trim_matches('"').trim();
+    match input {
+        "" => Ok(current.map(str::to_string)),
+        "auto" => Ok(None),
+        _ => Ok(Some(input.to_string())),
+    }
+}
@@ -0,0 +1,391 @@
+use crate::{
+    config::get_config_dir,
+    constants::{HISTORY_FILE_NAME, SNAPSHOTS_DIR_NAME},
+    dry_run::is_dry_run,
+    enums::{IsaacVersion, Operation},
+    persistent_game_data::PersistentGameData,
+    save_data_path::get_steam_user_id,
+};
+use anyhow::{bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use colored::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::{copy, create_dir_all, read, read_to_string, OpenOptions},
+    io::Write,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// One line of the history file. The history is never rewritten, only appended to, so that it can
+/// be used as evidence of what this program did to a save file.
+#[derive(Deserialize, Serialize)]
+pub struct HistoryEntry {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub operation: Operation,
+    pub isaac_version: Option<IsaacVersion>,
+    pub steam_user_id: Option<u32>,
+    pub save_file_slot: Option<usize>,
+    pub source_path: Option<Utf8PathBuf>,
+    pub destination_path: Utf8PathBuf,
+    /// The SHA-256 hash of the destination file before the operation, or `None` if it did not
+    /// exist.
+    pub hash_before: Option<String>,
+    pub hash_after: Option<String>,
+    pub options_ini_change: Option<String>,
+}
+
+/// The state of a file before an operation, which must be read before the file is changed.
+pub struct FileState {
+    hash: Option<String>,
+    isaac_version: Option<IsaacVersion>,
+}
+
+impl FileState {
+    /// A snapshot of the file is also saved, so that the operation can be undone.
+    pub fn read(path: &Utf8Path) -> Result<Self> {
+        let file_state = read_file_state(path);
+        if is_dry_run() {
+            return Ok(file_state);
+        }
+        if let Some(hash) = &file_state.hash {
+            save_snapshot(path, hash)?;
+        }
+
+        Ok(file_state)
+    }
+
+    /// For the files that are not put back by undoing, such as the backups, no snapshot is saved.
+    pub fn read_without_snapshot(path: &Utf8Path) -> Self {
+        read_file_state(path)
+    }
+}
+
+fn read_file_state(path: &Utf8Path) -> FileState {
+    match read(path) {
+        Ok(bytes) => FileState {
+            hash: Some(get_hash(&bytes)),
+            isaac_version: PersistentGameData::from_bytes(&bytes)
+                .ok()
+                .and_then(|persistent_game_data| persistent_game_data.get_isaac_version()),
+        },
+        Err(_) => FileState {
+            hash: None,
+            isaac_version: None,
+        },
+    }
+}
+
+pub fn get_hash(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+pub fn get_history_path() -> Result<Utf8PathBuf> {
+    Ok(get_config_dir()?.join(HISTORY_FILE_NAME))
+}
+
+/// The snapshots are named after the hash of their contents, so a file that is snapshotted many
+/// times is only stored once.
+fn get_snapshot_path(hash: &str) -> Result<Utf8PathBuf> {
+    Ok(get_config_dir()?.join(SNAPSHOTS_DIR_NAME).join(hash))
+}
+
+fn save_snapshot(path: &Utf8Path, hash: &str) -> Result<()> {
+    let snapshot_path = get_snapshot_path(hash)?;
+    if snapshot_path.exists() {
+        return Ok(());
+    }
+
+    if let Some(snapshots_dir) = snapshot_path.parent() {
+        create_dir_all(snapshots_dir).context(format!(
+            "Failed to create the following directory:\n{}",
+            snapshots_dir.to_string().green(),
+        ))?;
+    }
+    copy(path, &snapshot_path).context(format!(
+        "Failed to copy:\n{}\n-->\n{}",
+        path.to_string().green(),
+        snapshot_path.to_string().green(),
+    ))?;
+
+    Ok(())
+}
+
+/// Returns the contents of a file from before an operation, after checking that the snapshot was not
+/// changed.
+pub fn read_snapshot(hash: &str) -> Result<Vec<u8>> {
+    let snapshot_path = get_snapshot_path(hash)?;
+    let bytes = read(&snapshot_path).context(format!(
+        "Failed to read the snapshot:\n{}",
+        snapshot_path.to_string().green(),
+    ))?;
+    if get_hash(&bytes) != hash {
+        bail!(
+            "The following snapshot is corrupt:\n{}",
+            snapshot_path.to_string().green(),
+        );
+    }
+
+    Ok(bytes)
+}
+
+/// Returns `None` if the file does not exist.
+pub fn get_file_hash(path: &Utf8Path) -> Option<String> {
+    read_file_state(path).hash
+}
+
+/// Records an operation that changed the destination file.
+pub fn record_operation(
+    operation: Operation,
+    source_path: Option<&Utf8Path>,
+    destination_path: &Utf8Path,
+    state_before: FileState,
+) -> Result<()> {
+    record_operation_with_options_ini_change(
+        operation,
+        source_path,
+        destination_path,
+        state_before,
+        None,
+    )
+}
+
+pub fn record_operation_with_options_ini_change(
+    operation: Operation,
+    source_path: Option<&Utf8Path>,
+    destination_path: &Utf8Path,
+    state_before: FileState,
+    options_ini_change: Option<String>,
+) -> Result<()> {
+    // Nothing was changed.
+    if is_dry_run() {
+        return Ok(());
+    }
+
+    let state_after = read_file_state(destination_path);
+    let source_isaac_version = source_path.and_then(|path| read_file_state(path).isaac_version);
+
+    let history_entry = HistoryEntry {
+        timestamp: get_timestamp()?,
+        operation,
+        isaac_version: state_after
+            .isaac_version
+            .or(state_before.isaac_version)
+            .or(source_isaac_version),
+        steam_user_id: get_steam_user_id().ok(),
+        save_file_slot: get_save_file_slot(destination_path)
+            .or_else(|| source_path.and_then(get_save_file_slot)),
+        source_path: source_path.map(Utf8Path::to_path_buf),
+        destination_path: destination_path.to_path_buf(),
+        hash_before: state_before.hash,
+        hash_after: state_after.hash,
+        options_ini_change,
+    };
+
+    append_history_entry(&history_entry)
+}
+
+fn append_history_entry(history_entry: &HistoryEntry) -> Result<()> {
+    let history_path = get_history_path()?;
+    if let Some(history_dir) = history_path.parent() {
+        create_dir_all(history_dir).context(format!(
+            "Failed to create the following directory:\n{}",
+            history_dir.to_string().green(),
+        ))?;
+    }
+
+    let line =
+        serde_json::to_string(history_entry).context("Failed to serialize the history entry.")?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&history_path)
+        .context(format!(
+            "Failed to open the following file:\n{}",
+            history_path.to_string().green(),
+        ))?;
+    writeln!(file, "{}", line).context(format!(
+        "Failed to write data to the following path:\n{}",
+        history_path.to_string().green(),
+    ))
+}
+
+pub fn read_history() -> Result<Vec<HistoryEntry>> {
+    let history_path = get_history_path()?;
+    if !history_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let history = read_to_string(&history_path).context(format!(
+        "Failed to read the file:\n{}",
+        history_path.to_string().green(),
+    ))?;
+
+    history
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).context(format!(
+                "Failed to parse line {} of the file:\n{}",
+                i + 1,
+                history_path.to_string().green(),
+            ))
+        })
+        .collect()
+}
+
+pub fn history() -> Result<()> {
+    let history = read_history()?;
+    if history.is_empty() {
+        println!("This program has not changed any files yet.");
+        return Ok(());
+    }
+
+    for history_entry in &history {
+        print_history_entry(history_entry);
+    }
+    println!("The full history is in the following file:");
+    println!("{}", get_history_path()?.to_string().green());
+
+    Ok(())
+}
+
+fn print_history_entry(history_entry: &HistoryEntry) {
+    for line in get_history_entry_lines(history_entry) {
+        println!("{}", line);
+    }
+    println!();
+}
+
+pub fn get_history_entry_lines(history_entry: &HistoryEntry) -> Vec<String> {
+    let mut heading = vec![
+        format_timestamp(history_entry.timestamp),
+        history_entry.operation.to_string(),
+    ];
+    if let Some(isaac_version) = history_entry.isaac_version {
+        heading.push(isaac_version.to_string());
+    }
+    if let Some(save_file_slot) = history_entry.save_file_slot {
+        heading.push(format!("slot {}", save_file_slot));
+    }
+    if let Some(steam_user_id) = history_entry.steam_user_id {
+        heading.push(format!("Steam user {}", steam_user_id));
+    }
+
+    let mut lines = vec![heading.join(" - ")];
+    if let Some(source_path) = &history_entry.source_path {
+        lines.push(format!("  {}", source_path));
+        lines.push("  -->".to_string());
+    }
+    lines.push(format!("  {}", history_entry.destination_path));
+    if let Some(options_ini_change) = &history_entry.options_ini_change {
+        lines.push(format!("  {}", options_ini_change));
+    }
+    lines.push(format!(
+        "  {} --> {}",
+        format_hash(&history_entry.hash_before),
+        format_hash(&history_entry.hash_after),
+    ));
+
+    lines
+}
+
+fn format_hash(hash: &Option<String>) -> String {
+    match hash {
+        Some(hash) => hash.chars().take(12).collect(),
+        None => "[none]".to_string(),
+    }
+}
+
+/// e.g. "persistentgamedata2.dat" and "rep_persistentgamedata2.dat" are both slot 2.
+pub fn get_save_file_slot(path: &Utf8Path) -> Option<usize> {
+    let file_name = path.file_name()?;
+    let (_, suffix) = file_name.split_once("persistentgamedata")?;
+    let slot = suffix.strip_suffix(".dat")?;
+    slot.parse().ok()
+}
+
+pub fn get_timestamp() -> Result<u64> {
+    Ok(SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .context("Failed to get the current time.")?
+        .as_secs())
+}
+
+/// Formats the timestamp as UTC, e.g. "2023-09-30 18:04:12 UTC".
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86400;
+    let seconds = timestamp % 86400;
+
+    // From: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719468;
+    let era = z / 146097;
+    let day_of_era = z % 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02}:{:02} UTC",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        install::get_save_file_bytes,
+        test_utils::{FakeTree, STEAM_USER_ID},
+    };
+
+    #[test]
+    fn formats_timestamps_as_utc() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_timestamp(1696097052), "2023-09-30 18:04:12 UTC");
+    }
+
+    #[test]
+    fn formats_leap_days() {
+        assert_eq!(format_timestamp(951785999), "2000-02-29 00:59:59 UTC");
+        assert_eq!(format_timestamp(4107542399), "2100-02-28 23:59:59 UTC");
+    }
+
+    #[test]
+    fn records_history() {
+        let fake_tree = FakeTree::new();
+        fake_tree
+            .run(&["y", "5", "1", "1", "14", "1", "1", "2", "3", "1", "19"])
+            .unwrap();
+
+        let history = read_history().unwrap();
+        let operations: Vec<Operation> = history
+            .iter()
+            .map(|history_entry| history_entry.operation)
+            .collect();
+        assert!(operations == [Operation::Install, Operation::Copy, Operation::Delete]);
+
+        let save_file_hash = get_hash(&get_save_file_bytes(IsaacVersion::Repentance).unwrap());
+        let install = &history[0];
+        assert!(install.isaac_version == Some(IsaacVersion::Repentance));
+        assert_eq!(install.steam_user_id, Some(STEAM_USER_ID));
+        assert_eq!(install.save_file_slot, Some(1));
+        assert_eq!(install.hash_before, None);
+        assert_eq!(install.hash_after.as_ref(), Some(&save_file_hash));
+
+        let delete = &history[2];
+        assert_eq!(delete.hash_before.as_ref(), Some(&save_file_hash));
+        assert_eq!(delete.hash_after, None);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    challenges::get_challenge_ids,
+    collection::get_collectible_ids,
+    dry_run::{get_success_prefix, write_file},
+    enums::{IsaacVersion, Operation},
+    get_input::{confirm_overwrite_save_file, prompt_for_import_path},
+    history::{record_operation, FileState},
+    persistent_game_data::{read_persistent_game_data, PersistentGameData},
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+use std::fs::read;
+
+pub fn import(
+    (save_file_path, exists): &(Utf8PathBuf, bool),
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    let import_path = prompt_for_import_path()?;
+    if !import_path.is_file() {
+        bail!(
+            "The following file does not exist:\n{}",
+            import_path.to_string().green(),
+        );
+    }
+
+    // This checks the version in the header and the checksum.
+    let persistent_game_data = read_persistent_game_data(&import_path, isaac_version)?;
+    print_summary(&persistent_game_data, isaac_version);
+
+    if *exists {
+        let confirmed = confirm_overwrite_save_file(save_file_path)?;
+        if !confirmed {
+            return Ok(());
+        }
+    }
+
+    // The original bytes are copied so that nothing in the file is changed.
+    let save_file_bytes = read(&import_path).context(format!(
+        "Failed to read the file:\n{}",
+        import_path.to_string().green(),
+    ))?;
+    let state_before = FileState::read(save_file_path)?;
+    write_file(save_file_path, save_file_bytes)?;
+    record_operation(
+        Operation::Import,
+        Some(&import_path),
+        save_file_path,
+        state_before,
+    )?;
+
+    println!(
+        "{}:\n{}\n-->\n{}",
+        get_success_prefix("installed", "install"),
+        import_path.to_string().green(),
+        save_file_path.to_string().green(),
+    );
+
+    Ok(())
+}
+
+fn print_summary(persistent_game_data: &PersistentGameData, isaac_version: IsaacVersion) {
+    let achievement_ids: Vec<usize> = (1..persistent_game_data.achievements.len()).collect();
+    let challenge_ids = get_challenge_ids(persistent_game_data.challenges.len());
+    let collectible_ids =
+        get_collectible_ids(persistent_game_data.collectibles.len(), isaac_version);
+
+    println!("This is a valid {} save file with:", isaac_version);
+    print_summary_line(
+        "achievements",
+        &persistent_game_data.achievements,
+        &achievement_ids,
+    );
+    print_summary_line(
+        "challenges",
+        &persistent_game_data.challenges,
+        &challenge_ids,
+    );
+    print_summary_line(
+        "items on the collection page",
+        &persistent_game_data.collectibles,
+        &collectible_ids,
+    );
+    println!();
+}
+
+fn print_summary_line(name: &str, flags: &[u8], ids: &[usize]) {
+    let num_unlocked = ids.iter().filter(|id| flags[**id] != 0).count();
+    println!(
+        "- {} out of {} {}",
+        num_unlocked.to_string().green(),
+        ids.len(),
+        name,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        install::get_save_file_bytes, reset::get_new_persistent_game_data, test_utils::FakeTree,
+    };
+    use std::fs::write;
+
+    /// Imports the file at the given path into slot 1 of Repentance.
+    fn run_import(fake_tree: &FakeTree, import_path: &Utf8PathBuf, answers: &[&str]) {
+        let mut all_answers = vec!["y", "5", "5", "1", import_path.as_str()];
+        all_answers.extend_from_slice(answers);
+        all_answers.push("19");
+        fake_tree.run(&all_answers).unwrap();
+    }
+
+    #[test]
+    fn refuses_a_save_file_for_another_version() {
+        let fake_tree = FakeTree::new();
+        let import_path = fake_tree.root.join("import.dat");
+        write(
+            &import_path,
+            get_save_file_bytes(IsaacVersion::RepentancePlus).unwrap(),
+        )
+        .unwrap();
+
+        run_import(&fake_tree, &import_path, &[]);
+        assert!(!fake_tree
+            .get_save_file_path(IsaacVersion::Repentance, 1)
+            .exists());
+    }
+
+    #[test]
+    fn refuses_a_save_file_with_a_bad_checksum() {
+        let fake_tree = FakeTree::new();
+        let import_path = fake_tree.root.join("import.dat");
+        let mut bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        *bytes.last_mut().unwrap() ^= 0xff;
+        write(&import_path, bytes).unwrap();
+
+        run_import(&fake_tree, &import_path, &[]);
+        assert!(!fake_tree
+            .get_save_file_path(IsaacVersion::Repentance, 1)
+            .exists());
+    }
+
+    #[test]
+    fn asks_before_overwriting_a_slot() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        let existing_bytes = get_new_persistent_game_data(IsaacVersion::Repentance)
+            .unwrap()
+            .to_bytes();
+        write(&save_file_path, &existing_bytes).unwrap();
+        let import_path = fake_tree.root.join("import.dat");
+        let import_bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        write(&import_path, &import_bytes).unwrap();
+
+        run_import(&fake_tree, &import_path, &["n"]);
+        assert_eq!(read(&save_file_path).unwrap(), existing_bytes);
+
+        run_import(&fake_tree, &import_path, &["y"]);
+        assert_eq!(read(&save_file_path).unwrap(), import_bytes);
+    }
+}
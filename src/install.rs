@@ -1,41 +1,77 @@
 use crate::{
-    enums::IsaacVersion,
-    save_files::{
-        SAVE_FILE_AFTERBIRTH, SAVE_FILE_AFTERBIRTH_PLUS, SAVE_FILE_AFTERBIRTH_PLUS_BP5,
-        SAVE_FILE_REBIRTH, SAVE_FILE_REPENTANCE, SAVE_FILE_REPENTANCE_PLUS,
-    },
+    dry_run::{get_success_prefix, write_file},
+    enums::{IsaacVersion, Operation},
+    get_input::prompt_for_save_template,
+    history::{record_operation, FileState},
+    persistent_game_data::read_persistent_game_data,
+    save_files::get_embedded_save_file,
+    templates::{get_save_templates, SaveTemplate},
 };
 use anyhow::{Context, Result};
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
 use colored::Colorize;
-use std::fs::write;
+use std::fs::read;
 
-pub fn install(
-    (save_file_path, _exists): &(Utf8PathBuf, bool),
+pub fn install(save_file: &(Utf8PathBuf, bool), isaac_version: IsaacVersion) -> Result<()> {
+    let (save_file_bytes, description) = prompt_for_save_file_to_install(isaac_version)?;
+    install_save_file(save_file, &save_file_bytes, &description)
+}
+
+/// Returns the bytes of the save file to install, along with a description of it.
+pub fn prompt_for_save_file_to_install(isaac_version: IsaacVersion) -> Result<(Vec<u8>, String)> {
+    let save_templates = get_save_templates(isaac_version)?;
+    let save_template = prompt_for_save_template(&save_templates)?;
+    get_save_file_to_install(isaac_version, save_template)
+}
+
+/// Use `None` for the built-in fully-unlocked save file.
+pub fn get_save_file_to_install(
     isaac_version: IsaacVersion,
-) -> Result<()> {
-    let save_file_bytes = get_save_file_bytes(isaac_version);
+    save_template: Option<&SaveTemplate>,
+) -> Result<(Vec<u8>, String)> {
+    match save_template {
+        None => Ok((
+            get_save_file_bytes(isaac_version)?,
+            "a fully-unlocked save file".to_string(),
+        )),
+        Some(save_template) => {
+            // This checks the version in the header and the checksum.
+            read_persistent_game_data(&save_template.save_file_path, isaac_version)?;
+            let save_file_bytes = read(&save_template.save_file_path).context(format!(
+                "Failed to read the file:\n{}",
+                save_template.save_file_path.to_string().green(),
+            ))?;
+            Ok((
+                save_file_bytes,
+                format!("the \"{}\" save file", save_template.name),
+            ))
+        }
+    }
+}
 
-    write(save_file_path, save_file_bytes).context(format!(
-        "Failed to write data to the following path:\n{}",
-        save_file_path.to_string().green(),
-    ))?;
+pub fn install_save_file(
+    (save_file_path, _exists): &(Utf8PathBuf, bool),
+    save_file_bytes: &[u8],
+    description: &str,
+) -> Result<()> {
+    install_save_file_bytes(save_file_path, save_file_bytes)?;
 
     println!(
-        "Successfully installed a fully-unlocked save file to:\n{}",
+        "{} {} to:\n{}",
+        get_success_prefix("installed", "install"),
+        description,
         save_file_path.to_string().green(),
     );
 
     Ok(())
 }
 
-fn get_save_file_bytes(isaac_version: IsaacVersion) -> &'static [u8] {
-    match isaac_version {
-        IsaacVersion::Rebirth => SAVE_FILE_REBIRTH,
-        IsaacVersion::Afterbirth => SAVE_FILE_AFTERBIRTH,
-        IsaacVersion::AfterbirthPlus => SAVE_FILE_AFTERBIRTH_PLUS,
-        IsaacVersion::AfterbirthPlusBP5 => SAVE_FILE_AFTERBIRTH_PLUS_BP5,
-        IsaacVersion::Repentance => SAVE_FILE_REPENTANCE,
-        IsaacVersion::RepentancePlus => SAVE_FILE_REPENTANCE_PLUS,
-    }
+pub fn install_save_file_bytes(save_file_path: &Utf8Path, save_file_bytes: &[u8]) -> Result<()> {
+    let state_before = FileState::read(save_file_path)?;
+    write_file(save_file_path, save_file_bytes)?;
+    record_operation(Operation::Install, None, save_file_path, state_before)
+}
+
+pub fn get_save_file_bytes(isaac_version: IsaacVersion) -> Result<Vec<u8>> {
+    get_embedded_save_file(isaac_version).decompress()
 }
@@ -1,60 +1,200 @@
 use crate::{
     backup::backup,
+    batch::batch,
+    bestiary::bestiary,
+    challenges::challenges,
     change_steam_cloud::change_steam_cloud,
+    collection::collection,
+    config::{read_config, update_config},
+    convert::convert,
     delete::delete,
+    diff::diff,
+    dry_run::{is_dry_run, print_dry_run_actions, print_dry_run_mode, set_dry_run},
     enums::{Activity, IsaacVersion},
+    errors::{ErrorKind, InstallerError},
     get_input::{
-        check_pirate, prompt_for_activity, prompt_for_isaac_version, prompt_for_save_file_slot,
-        prompt_turn_steam_cloud_off,
+        check_pirate, confirm_set_up_paths, prompt_for_activity, prompt_for_isaac_version,
+        prompt_for_save_file_slot, prompt_for_save_file_slots, prompt_turn_steam_cloud_off,
     },
+    import::import,
     install::install,
+    print_error,
+    progress::progress,
+    reset::reset,
     save_data_path::{
         get_documents_save_data_path, get_steam_cloud_enabled, get_steam_save_data_path,
     },
+    set_up_paths::set_up_paths,
+    slots::manage_slots,
+    statistics::statistics,
+    undo::undo,
 };
-use anyhow::{bail, Context, Result};
+use anyhow::{bail, Context, Error, Result};
 use camino::{Utf8Path, Utf8PathBuf};
+#[cfg(windows)]
 use colored::control::set_virtual_terminal;
 use colored::*;
 use sysinfo::{System, SystemExt};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
+const ISAAC_PROCESS_NAME: &str = "isaac-ng.exe";
 
 pub fn isaac_save_installer() -> Result<()> {
+    #[cfg(windows)]
     set_virtual_terminal(true).expect("Failed to set the virtual terminal setting.");
     print_banner();
+    if is_dry_run() {
+        print_dry_run_mode();
+    }
     check_if_isaac_open()?;
     check_pirate()?;
 
-    let isaac_version = prompt_for_isaac_version()?;
-    let steam_save_data_path = get_steam_save_data_path()?;
-    let documents_save_data_path = get_documents_save_data_path(isaac_version)?;
-    let steam_cloud_enabled_initial = get_steam_cloud_enabled(&documents_save_data_path)?;
-    let steam_cloud_enabled =
-        prompt_turn_steam_cloud_off(&documents_save_data_path, steam_cloud_enabled_initial)?;
+    let mut isaac_version = prompt_for_isaac_version(read_config()?.last_isaac_version)?;
+    update_config(|config| config.last_isaac_version = Some(isaac_version))?;
+    let mut prompted_steam_cloud = false;
 
-    // TODO: Verify "log.txt" location.
+    loop {
+        let documents_save_data_path = match get_documents_save_data_path(isaac_version) {
+            Ok(documents_save_data_path) => documents_save_data_path,
+            Err(err) => {
+                offer_set_up_paths(err)?;
+                continue;
+            }
+        };
+        if !prompted_steam_cloud {
+            let steam_cloud_enabled = get_steam_cloud_enabled(&documents_save_data_path)?;
+            if prompt_turn_steam_cloud_off(&documents_save_data_path, steam_cloud_enabled)?
+                && is_dry_run()
+            {
+                println!();
+                print_dry_run_actions();
+                println!();
+            }
+            prompted_steam_cloud = true;
+        }
 
-    let save_data_path: &Utf8Path = match steam_cloud_enabled {
-        true => &steam_save_data_path,
-        false => &documents_save_data_path,
-    };
+        // The setting is read again every time, since the previous activity may have changed it.
+        let steam_cloud_enabled = get_steam_cloud_enabled(&documents_save_data_path)?;
+
+        // TODO: Verify "log.txt" location.
+
+        // The Steam directory is only needed for Steam Cloud, so it is only looked for then.
+        let save_data_path = match steam_cloud_enabled {
+            true => match get_steam_save_data_path() {
+                Ok(steam_save_data_path) => steam_save_data_path,
+                Err(err) => {
+                    offer_set_up_paths(err)?;
+                    continue;
+                }
+            },
+            false => documents_save_data_path.clone(),
+        };
+
+        let existing_save_files =
+            get_existing_save_files(isaac_version, &save_data_path, steam_cloud_enabled);
+        print_save_files(&existing_save_files, isaac_version)?;
+
+        let activity = prompt_for_activity()?;
+        match activity {
+            Activity::ChangeVersion => {
+                isaac_version = prompt_for_isaac_version(Some(isaac_version))?;
+                update_config(|config| config.last_isaac_version = Some(isaac_version))?;
+                prompted_steam_cloud = false;
+            }
+            Activity::ToggleDryRun => {
+                set_dry_run(!is_dry_run());
+                print_dry_run_mode();
+            }
+            Activity::Quit => return Ok(()),
+            _ => {
+                let result = run_activity(
+                    activity,
+                    isaac_version,
+                    &documents_save_data_path,
+                    steam_cloud_enabled,
+                    &existing_save_files,
+                );
+                match result {
+                    Ok(()) if is_dry_run() => println!(),
+                    Ok(()) => {}
+                    Err(err) => print_error(&err),
+                }
+                print_dry_run_actions();
+                println!();
+            }
+        }
+    }
+}
+
+/// If the save files cannot be found automatically, the user can type the paths instead of having
+/// to start over.
+fn offer_set_up_paths(err: Error) -> Result<()> {
+    println!("{} {}", "Error:".red(), err);
+    println!();
+    if !confirm_set_up_paths()? {
+        bail!(InstallerError::new(
+            ErrorKind::of(&err),
+            "Failed to find the save files.",
+        ));
+    }
+
+    set_up_paths()?;
+    println!();
+
+    Ok(())
+}
 
-    let existing_save_files =
-        get_existing_save_files(isaac_version, save_data_path, steam_cloud_enabled);
-    print_save_files(&existing_save_files)?;
+pub fn run_activity(
+    activity: Activity,
+    isaac_version: IsaacVersion,
+    documents_save_data_path: &Utf8Path,
+    steam_cloud_enabled: bool,
+    existing_save_files: &[(Utf8PathBuf, bool)],
+) -> Result<()> {
+    // The game might have been opened since the last activity.
+    check_if_isaac_open()?;
 
-    let activity = prompt_for_activity()?;
     if activity == Activity::ChangeSteamCloud {
-        return change_steam_cloud(&documents_save_data_path, steam_cloud_enabled);
-    } else if activity == Activity::ManuallyInstall {
-        println!("First, download the fully unlocked save file from here:");
-        println!("https://github.com/Zamiell/isaac-save-installer/tree/main/saves");
-        println!("Second, rename the downloaded file to have the same name as your existing save file, and then overwrite your existing save file with the downloaded one.");
-        return Ok(());
+        return change_steam_cloud(documents_save_data_path, steam_cloud_enabled);
+    } else if activity == Activity::Diff {
+        return diff(existing_save_files);
+    } else if activity == Activity::ManageSlots {
+        return manage_slots(existing_save_files);
+    } else if activity == Activity::Undo {
+        return undo();
+    } else if activity == Activity::SetUpPaths {
+        return set_up_paths();
     }
 
-    let save_file_slot = prompt_for_save_file_slot(activity)?;
+    let last_save_file_slot = read_config()?.last_save_file_slot;
+    let save_file_slot = match activity {
+        Activity::Install | Activity::Backup | Activity::Delete => {
+            let save_file_slots = prompt_for_save_file_slots(activity, last_save_file_slot)?;
+            match save_file_slots.as_slice() {
+                [save_file_slot] => *save_file_slot,
+                _ => {
+                    return batch(
+                        activity,
+                        &save_file_slots,
+                        existing_save_files,
+                        isaac_version,
+                    )
+                }
+            }
+        }
+        _ => prompt_for_save_file_slot(activity, last_save_file_slot)?,
+    };
+    update_config(|config| config.last_save_file_slot = Some(save_file_slot))?;
+
+    run_activity_for_slot(activity, save_file_slot, isaac_version, existing_save_files)
+}
+
+pub fn run_activity_for_slot(
+    activity: Activity,
+    save_file_slot: usize,
+    isaac_version: IsaacVersion,
+    existing_save_files: &[(Utf8PathBuf, bool)],
+) -> Result<()> {
     let save_file_index = save_file_slot - 1;
     let save_file = existing_save_files.get(save_file_index).context(format!(
         "Failed to get the save file at index: {}",
@@ -63,10 +203,24 @@ pub fn isaac_save_installer() -> Result<()> {
 
     match activity {
         Activity::Install => install(save_file, isaac_version),
+        Activity::Import => import(save_file, isaac_version),
         Activity::Backup => backup(save_file, save_file_slot),
         Activity::Delete => delete(save_file, save_file_slot),
+        Activity::Statistics => statistics(save_file, save_file_slot, isaac_version),
+        Activity::Collection => collection(save_file, save_file_slot, isaac_version),
+        Activity::Challenges => challenges(save_file, save_file_slot, isaac_version),
+        Activity::Bestiary => bestiary(save_file, save_file_slot, isaac_version),
+        Activity::Progress => progress(save_file, save_file_slot, isaac_version),
+        Activity::Convert => convert(save_file, save_file_slot, isaac_version),
+        Activity::Reset => reset(save_file, isaac_version),
         Activity::ChangeSteamCloud => unreachable!(),
-        Activity::ManuallyInstall => unreachable!(),
+        Activity::Diff => unreachable!(),
+        Activity::ManageSlots => unreachable!(),
+        Activity::Undo => unreachable!(),
+        Activity::SetUpPaths => unreachable!(),
+        Activity::ToggleDryRun => unreachable!(),
+        Activity::ChangeVersion => unreachable!(),
+        Activity::Quit => unreachable!(),
     }
 }
 
@@ -80,17 +234,25 @@ fn print_banner() {
     println!();
 }
 
-fn check_if_isaac_open() -> Result<()> {
-    let system = System::new_all();
-    let isaac_processes = system.processes_by_exact_name("isaac-ng.exe");
-
-    match isaac_processes.count() {
-        0 => Ok(()),
-        _ => bail!("You are currently running The Binding of Isaac: Rebirth.\nClose the game before you run this installer."),
+pub fn check_if_isaac_open() -> Result<()> {
+    match is_isaac_open(&mut System::new()) {
+        false => Ok(()),
+        true => bail!(InstallerError::new(
+            ErrorKind::GameRunning,
+            "You are currently running The Binding of Isaac: Rebirth.",
+        )),
     }
 }
 
-fn get_existing_save_files(
+/// The same `System` can be passed in again, so that the list of processes does not have to be
+/// created from scratch every time.
+pub fn is_isaac_open(system: &mut System) -> bool {
+    system.refresh_processes();
+    let mut isaac_processes = system.processes_by_exact_name(ISAAC_PROCESS_NAME);
+    isaac_processes.next().is_some()
+}
+
+pub fn get_existing_save_files(
     isaac_version: IsaacVersion,
     save_data_path: &Utf8Path,
     steam_cloud_enabled: bool,
@@ -111,7 +273,7 @@ fn get_existing_save_files(
     save_file_paths
 }
 
-fn get_file_name_prefix(isaac_version: IsaacVersion, steam_cloud_enabled: bool) -> String {
+pub fn get_file_name_prefix(isaac_version: IsaacVersion, steam_cloud_enabled: bool) -> String {
     if !steam_cloud_enabled {
         return String::from("");
     }
@@ -127,8 +289,11 @@ fn get_file_name_prefix(isaac_version: IsaacVersion, steam_cloud_enabled: bool)
     String::from(prefix)
 }
 
-fn print_save_files(existing_save_files: &[(Utf8PathBuf, bool)]) -> Result<()> {
-    println!("Your current save files are as follows:");
+fn print_save_files(
+    existing_save_files: &[(Utf8PathBuf, bool)],
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    println!("Your current {} save files are as follows:", isaac_version);
     for (i, (save_file_path, exists)) in existing_save_files.iter().enumerate() {
         let value = match exists {
             true => save_file_path.to_string().green(),
@@ -140,3 +305,312 @@ fn print_save_files(existing_save_files: &[(Utf8PathBuf, bool)]) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backup_store::read_backup_index,
+        get_input::{set_input, ScriptedInput},
+        install::get_save_file_bytes,
+        persistent_game_data::parse_persistent_game_data,
+        sandbox::set_sandbox_root,
+        test_utils::{create_documents_dir, get_documents_dir_name, lock, FakeTree, STEAM_USER_ID},
+    };
+    use std::fs::{create_dir_all, read, read_to_string, write};
+    use tempfile::TempDir;
+
+    #[test]
+    fn installs_fully_unlocked_save_file() {
+        let fake_tree = FakeTree::new();
+        fake_tree.run(&["y", "5", "1", "1", "19"]).unwrap();
+
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        assert_eq!(
+            read(save_file_path).unwrap(),
+            get_save_file_bytes(IsaacVersion::Repentance).unwrap(),
+        );
+    }
+
+    #[test]
+    fn installs_to_all_slots() {
+        let fake_tree = FakeTree::new();
+        fake_tree.run(&["y", "5", "1", "all", "19"]).unwrap();
+
+        for save_file_slot in 1..=3 {
+            assert!(fake_tree
+                .get_save_file_path(IsaacVersion::Repentance, save_file_slot)
+                .exists());
+        }
+    }
+
+    #[test]
+    fn deletes_save_file() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 2);
+        write(
+            &save_file_path,
+            get_save_file_bytes(IsaacVersion::Repentance).unwrap(),
+        )
+        .unwrap();
+
+        fake_tree.run(&["y", "5", "3", "2", "19"]).unwrap();
+
+        assert!(!save_file_path.exists());
+    }
+
+    #[test]
+    fn resets_save_file() {
+        let fake_tree = FakeTree::new();
+        fake_tree.run(&["y", "5", "13", "1", "19"]).unwrap();
+
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        let persistent_game_data = parse_persistent_game_data(&save_file_path).unwrap();
+        assert!(persistent_game_data
+            .achievements
+            .iter()
+            .all(|achievement| *achievement == 0));
+    }
+
+    #[test]
+    fn copies_save_file_between_slots() {
+        let fake_tree = FakeTree::new();
+        let save_file_bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        write(
+            fake_tree.get_save_file_path(IsaacVersion::Repentance, 1),
+            &save_file_bytes,
+        )
+        .unwrap();
+
+        fake_tree
+            .run(&["y", "5", "14", "1", "1", "2", "19"])
+            .unwrap();
+
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 2);
+        assert_eq!(read(save_file_path).unwrap(), save_file_bytes);
+    }
+
+    #[test]
+    fn swaps_into_an_empty_slot_without_a_backup() {
+        let fake_tree = FakeTree::new();
+        let save_file_bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        write(
+            fake_tree.get_save_file_path(IsaacVersion::Repentance, 2),
+            &save_file_bytes,
+        )
+        .unwrap();
+
+        // Nothing is overwritten, so there is no confirmation prompt.
+        fake_tree
+            .run(&["y", "5", "14", "3", "1", "2", "19"])
+            .unwrap();
+
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        assert_eq!(read(save_file_path).unwrap(), save_file_bytes);
+        assert!(!fake_tree
+            .get_save_file_path(IsaacVersion::Repentance, 2)
+            .exists());
+        assert!(read_backup_index().unwrap().is_empty());
+    }
+
+    #[test]
+    fn turns_steam_cloud_on() {
+        let fake_tree = FakeTree::new();
+        fake_tree.run(&["y", "5", "4", "y", "19"]).unwrap();
+
+        let options_ini_path = get_documents_save_data_path(IsaacVersion::Repentance)
+            .unwrap()
+            .join("options.ini");
+        assert!(read_to_string(options_ini_path)
+            .unwrap()
+            .contains("SteamCloud=1"));
+    }
+
+    #[test]
+    fn switches_game_version() {
+        let fake_tree = FakeTree::new();
+        fake_tree
+            .run(&["y", "5", "18", "6", "1", "1", "19"])
+            .unwrap();
+
+        assert!(!fake_tree
+            .get_save_file_path(IsaacVersion::Repentance, 1)
+            .exists());
+        assert!(fake_tree
+            .get_save_file_path(IsaacVersion::RepentancePlus, 1)
+            .exists());
+    }
+
+    #[test]
+    fn remembers_last_version_and_slot() {
+        let fake_tree = FakeTree::new();
+        fake_tree.run(&["y", "6", "1", "2", "19"]).unwrap();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::RepentancePlus, 2);
+        assert!(save_file_path.exists());
+
+        // Pressing enter selects the version and the slot from the last time.
+        fake_tree.run(&["y", "", "3", "", "19"]).unwrap();
+        assert!(!save_file_path.exists());
+    }
+
+    #[test]
+    fn sets_up_paths() {
+        let fake_tree = FakeTree::new();
+        let documents_dir = fake_tree.root.join("Other Documents");
+        create_documents_dir(&documents_dir);
+
+        fake_tree
+            .run(&[
+                "y",
+                "5",
+                "16",
+                documents_dir.as_str(),
+                "",
+                "",
+                "1",
+                "1",
+                "19",
+            ])
+            .unwrap();
+
+        assert!(documents_dir
+            .join("My Games")
+            .join(get_documents_dir_name(IsaacVersion::Repentance))
+            .join("persistentgamedata1.dat")
+            .exists());
+        assert_eq!(read_config().unwrap().documents_dir, Some(documents_dir));
+    }
+
+    #[test]
+    fn reports_empty_slot() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+
+        let err = run_activity_for_slot(
+            Activity::Delete,
+            1,
+            IsaacVersion::Repentance,
+            &[(save_file_path, false)],
+        )
+        .unwrap_err();
+
+        assert_eq!(ErrorKind::of(&err), ErrorKind::SlotEmpty);
+        assert_eq!(ErrorKind::of(&err).exit_code(), 6);
+    }
+
+    #[test]
+    fn reports_corrupt_save_file() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        let mut save_file_bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        let last_index = save_file_bytes.len() - 1;
+        save_file_bytes[last_index] ^= 0xff;
+        write(&save_file_path, save_file_bytes).unwrap();
+
+        let Err(err) = parse_persistent_game_data(&save_file_path) else {
+            panic!("The corrupt save file was parsed.");
+        };
+
+        assert_eq!(ErrorKind::of(&err), ErrorKind::CorruptSave);
+    }
+
+    #[test]
+    fn reports_missing_save_directory() {
+        let _lock = lock();
+        let temp_dir = TempDir::new().unwrap();
+        let root = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf()).unwrap();
+        set_sandbox_root(Some(root));
+        set_input(Box::new(ScriptedInput::new(
+            ["y", "5", "n"]
+                .iter()
+                .map(|answer| answer.to_string())
+                .collect(),
+        )));
+
+        let err = isaac_save_installer().unwrap_err();
+
+        assert_eq!(ErrorKind::of(&err), ErrorKind::SaveDirNotFound);
+    }
+
+    #[test]
+    fn continues_after_failed_activity() {
+        let fake_tree = FakeTree::new();
+
+        // Deleting an empty slot fails, but the next activity still runs.
+        fake_tree
+            .run(&["y", "5", "3", "1", "1", "1", "19"])
+            .unwrap();
+
+        assert!(fake_tree
+            .get_save_file_path(IsaacVersion::Repentance, 1)
+            .exists());
+    }
+
+    #[test]
+    fn asks_again_after_invalid_selection() {
+        let fake_tree = FakeTree::new();
+
+        // "0" is not a valid selection either, even though it is a number.
+        fake_tree
+            .run(&["y", "5", "0", "abc", "20", "1", "1", "19"])
+            .unwrap();
+
+        assert!(fake_tree
+            .get_save_file_path(IsaacVersion::Repentance, 1)
+            .exists());
+    }
+
+    #[test]
+    fn stops_when_out_of_answers() {
+        let fake_tree = FakeTree::new();
+        assert!(fake_tree.run(&["y", "5"]).is_err());
+    }
+
+    #[test]
+    fn refuses_pirated_copy() {
+        let fake_tree = FakeTree::new();
+        assert!(fake_tree.run(&["n"]).is_err());
+    }
+
+    #[test]
+    fn installs_inside_sandbox_root() {
+        let _lock = lock();
+        let temp_dir = TempDir::new().unwrap();
+        let root = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf()).unwrap();
+        let version_dir = root
+            .join("Users")
+            .join("Someone")
+            .join("Documents")
+            .join("My Games")
+            .join(get_documents_dir_name(IsaacVersion::Repentance));
+        create_dir_all(&version_dir).unwrap();
+        write(version_dir.join("log.txt"), "").unwrap();
+        write(version_dir.join("options.ini"), "[Options]\nSteamCloud=1\n").unwrap();
+        write(
+            root.join("registry.ini"),
+            "[Software\\Valve\\Steam]\nSteamPath=C:\\Program Files (x86)\\Steam\n\n[Software\\Valve\\Steam\\ActiveProcess]\nActiveUser=12345\n",
+        )
+        .unwrap();
+        let steam_save_data_path = root
+            .join("Program Files (x86)")
+            .join("Steam")
+            .join("userdata")
+            .join(STEAM_USER_ID.to_string())
+            .join("250900")
+            .join("remote");
+        create_dir_all(&steam_save_data_path).unwrap();
+
+        set_sandbox_root(Some(root.clone()));
+        set_input(Box::new(ScriptedInput::new(
+            ["y", "5", "n", "1", "1", "19"]
+                .iter()
+                .map(|answer| answer.to_string())
+                .collect(),
+        )));
+        isaac_save_installer().unwrap();
+
+        assert!(steam_save_data_path
+            .join("rep_persistentgamedata1.dat")
+            .exists());
+    }
+}
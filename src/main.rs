@@ -1,44 +1,144 @@
-use anyhow::Error;
+use anyhow::{Error, Result};
 
+use args::{parse_args, Args, BackupsCommand, Command};
+use backup_store::{label_backup, list_backups, prune, RetentionPolicy};
 use colored::*;
-use get_input::prompt_for_user_to_hit_enter;
+use config::{apply_save_data_path_overrides, read_config};
+use dry_run::set_dry_run;
+use errors::ErrorKind;
+use get_input::{prompt_for_user_to_hit_enter, set_input, ScriptedInput};
+use history::history;
 use isaac_save_installer::isaac_save_installer;
+use sandbox::set_sandbox_root;
+use save_data_path::SaveDataPathOverrides;
+use std::io::{stdin, stdout, IsTerminal};
+use tui::tui;
+use watch::watch;
 
+mod achievements;
+mod args;
 mod backup;
+mod backup_store;
+mod batch;
+mod bestiary;
+mod challenge_names;
+mod challenges;
 mod change_steam_cloud;
+mod checksum;
+mod collectible_names;
+mod collection;
+mod config;
 mod constants;
+mod convert;
 mod delete;
+mod diff;
+mod dry_run;
+mod entity_names;
 mod enums;
+mod errors;
 mod get_input;
+mod history;
+mod import;
 mod install;
 mod isaac_save_installer;
+mod persistent_game_data;
+mod progress;
+mod reset;
+mod sandbox;
 mod save_data_path;
 mod save_files;
+mod set_up_paths;
+mod settings;
+mod slots;
+mod statistics;
+mod templates;
+#[cfg(test)]
+mod test_utils;
+mod tui;
+mod undo;
 mod utils;
+mod watch;
 
 fn main() {
-    match isaac_save_installer() {
-        Ok(()) => quit(false),
-        Err(err) => error(&err),
+    let args = parse_args();
+
+    // The pause keeps the window open when the program is started by double-clicking it. It would
+    // only get in the way of a subcommand or of input that is not typed.
+    let pause = args.as_ref().is_ok_and(|args| args.command.is_none()) && stdin().is_terminal();
+
+    match args.and_then(run) {
+        Ok(()) => quit(0, pause),
+        Err(err) => error(&err, pause),
+    }
+}
+
+fn run(args: Args) -> Result<()> {
+    set_sandbox_root(args.root.clone());
+    set_dry_run(args.dry_run);
+    apply_save_data_path_overrides(SaveDataPathOverrides {
+        documents_dir: args.documents_dir.clone(),
+        steam_dir: args.steam_dir.clone(),
+        steam_user_id: args.steam_user_id,
+    })?;
+    if let Some(answers_path) = &args.answers_path {
+        set_input(Box::new(ScriptedInput::from_file(answers_path)?));
+    }
+
+    match args.command {
+        Some(Command::History) => history(),
+        Some(Command::Watch) => watch(&get_retention_policy(&args)?),
+        Some(Command::Backups(BackupsCommand::List)) => list_backups(),
+        Some(Command::Backups(BackupsCommand::Prune)) => prune(&get_retention_policy(&args)?),
+        Some(Command::Backups(BackupsCommand::Label {
+            ref hash,
+            ref label,
+        })) => label_backup(hash, label),
+        None if use_tui(&args) => tui(),
+        None => isaac_save_installer(),
+    }
+}
+
+/// The flags on the command line are used instead of the retention policy from the config file.
+fn get_retention_policy(args: &Args) -> Result<RetentionPolicy> {
+    let mut retention_policy = read_config()?.retention;
+    if let Some(keep_last) = args.keep_last {
+        retention_policy.keep_last = keep_last;
     }
+    if let Some(keep_daily) = args.keep_daily {
+        retention_policy.keep_daily = keep_daily;
+    }
+    if let Some(keep_weekly) = args.keep_weekly {
+        retention_policy.keep_weekly = keep_weekly;
+    }
+
+    Ok(retention_policy)
+}
+
+/// The full-screen interface needs an interactive terminal, so the prompts are used when the input or
+/// output is redirected.
+fn use_tui(args: &Args) -> bool {
+    !args.no_tui && args.answers_path.is_none() && stdin().is_terminal() && stdout().is_terminal()
 }
 
-pub fn error(msg: &Error) -> ! {
+pub fn error(msg: &Error, pause: bool) -> ! {
+    print_error(msg);
+    quit(ErrorKind::of(msg).exit_code(), pause);
+}
+
+pub fn print_error(msg: &Error) {
     println!("{} {}", "Error:".red(), msg);
     println!();
-    println!("If you think the error is related to permissions, then you can try running this program again as an administrator:");
-    println!("https://www.digitalcitizen.life/run-as-admin/");
-    println!();
-    quit(true);
+    if let Some(hint) = ErrorKind::of(msg).hint() {
+        println!("{}", hint);
+        println!();
+    }
 }
 
-pub fn quit(errored: bool) -> ! {
-    println!("You can now close this window.");
-    prompt_for_user_to_hit_enter().ok();
+pub fn quit(exit_code: i32, pause: bool) -> ! {
+    if pause {
+        println!("You can now close this window.");
+        prompt_for_user_to_hit_enter().ok();
+    }
 
-    let exit_code = match errored {
-        true => 1,
-        false => 0,
-    };
     std::process::exit(exit_code);
 }
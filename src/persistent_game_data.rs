@@ -0,0 +1,528 @@
+use crate::{
+    checksum::get_checksum,
+    dry_run::write_file,
+    enums::IsaacVersion,
+    errors::{ErrorKind, InstallerError},
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8Path;
+use colored::Colorize;
+use std::fs::read;
+
+const FILE_TAG_PREFIX: &[u8] = b"ISAACNGSAVE";
+const FILE_TAG_LENGTH: usize = 16;
+const HEADER_VALUE_LENGTH: usize = 4;
+const FOOTER_VALUE_LENGTH: usize = 4;
+const CHECKSUM_LENGTH: usize = 4;
+const SECTION_HEADER_LENGTH: usize = 12;
+
+const SECTION_ACHIEVEMENTS: u32 = 1;
+const SECTION_COUNTERS: u32 = 2;
+const SECTION_LEVEL_COUNTERS: u32 = 3;
+const SECTION_COLLECTIBLES: u32 = 4;
+const SECTION_MINIBOSSES: u32 = 5;
+const SECTION_BOSSES: u32 = 6;
+const SECTION_CHALLENGES: u32 = 7;
+const SECTION_CUTSCENE_COUNTERS: u32 = 8;
+const SECTION_GAME_SETTINGS: u32 = 9;
+const SECTION_SPECIAL_SEEDS: u32 = 10;
+const SECTION_BESTIARY: u32 = 11;
+
+/// A parsed "persistentgamedata.dat" file.
+///
+/// Every list is indexed by the in-game ID, so element 0 is unused (e.g. the first achievement is
+/// at index 1).
+#[derive(Clone)]
+pub struct PersistentGameData {
+    file_tag: [u8; FILE_TAG_LENGTH],
+    header_value: [u8; HEADER_VALUE_LENGTH],
+    pub achievements: Vec<u8>,
+    pub counters: Vec<u32>,
+    pub level_counters: Vec<u32>,
+    pub collectibles: Vec<u8>,
+    pub minibosses: Vec<u8>,
+    pub bosses: Vec<u8>,
+    pub challenges: Vec<u8>,
+    pub cutscene_counters: Vec<u32>,
+    pub game_settings: Vec<u32>,
+    /// Only present in Afterbirth and later.
+    pub special_seeds: Option<Vec<u8>>,
+    /// Only present in Afterbirth+ and later.
+    pub bestiary: Option<Bestiary>,
+    footer_value: [u8; FOOTER_VALUE_LENGTH],
+    /// The checksum that was read from the file. It is only written back for Rebirth saves, since
+    /// their checksum is not known.
+    checksum: u32,
+}
+
+#[derive(Clone)]
+pub struct Bestiary {
+    /// Afterbirth+ saves report one more list than they actually contain, so the original value is
+    /// kept as-is.
+    count: u32,
+    pub lists: Vec<BestiaryList>,
+}
+
+#[derive(Clone)]
+pub struct BestiaryList {
+    /// Corresponds to `BestiaryStat`.
+    pub list_type: u32,
+    pub entries: Vec<BestiaryEntry>,
+}
+
+#[derive(Clone, Copy)]
+pub struct BestiaryEntry {
+    /// The entity type, variant, and sub-type, packed together. (e.g. 10.1.0 is 0x00A00100)
+    pub entity_key: u32,
+    pub value: u32,
+}
+
+impl BestiaryEntry {
+    pub fn get_entity_type(&self) -> u32 {
+        self.entity_key >> 20
+    }
+
+    pub fn get_entity_variant(&self) -> u32 {
+        (self.entity_key >> 8) & 0xFFF
+    }
+}
+
+impl PersistentGameData {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = SaveReader::new(bytes)?;
+
+        let file_tag = reader.read_array()?;
+        if !file_tag.starts_with(FILE_TAG_PREFIX) {
+            bail!("The file does not start with the \"ISAACNGSAVE\" tag, so it is not a save file for The Binding of Isaac: Rebirth.");
+        }
+        let header_value = reader.read_array()?;
+
+        let achievements = reader.read_flag_section(SECTION_ACHIEVEMENTS)?;
+        let counters = reader.read_counter_section(SECTION_COUNTERS)?;
+        let level_counters = reader.read_counter_section(SECTION_LEVEL_COUNTERS)?;
+        let collectibles = reader.read_flag_section(SECTION_COLLECTIBLES)?;
+        let minibosses = reader.read_flag_section(SECTION_MINIBOSSES)?;
+        let bosses = reader.read_flag_section(SECTION_BOSSES)?;
+        let challenges = reader.read_flag_section(SECTION_CHALLENGES)?;
+        let cutscene_counters = reader.read_counter_section(SECTION_CUTSCENE_COUNTERS)?;
+        let game_settings = reader.read_counter_section(SECTION_GAME_SETTINGS)?;
+
+        let special_seeds = match reader.has_more_sections() {
+            true => Some(reader.read_flag_section(SECTION_SPECIAL_SEEDS)?),
+            false => None,
+        };
+        let bestiary = match reader.has_more_sections() {
+            true => Some(reader.read_bestiary_section()?),
+            false => None,
+        };
+
+        let footer_value = reader.read_array()?;
+        let checksum_bytes: [u8; CHECKSUM_LENGTH] = reader.read_array()?;
+        if reader.offset != bytes.len() {
+            bail!(
+                "The save file has {} unexpected bytes at the end.",
+                bytes.len() - reader.offset,
+            );
+        }
+
+        let checksum = u32::from_le_bytes(checksum_bytes);
+        let persistent_game_data = Self {
+            file_tag,
+            header_value,
+            achievements,
+            counters,
+            level_counters,
+            collectibles,
+            minibosses,
+            bosses,
+            challenges,
+            cutscene_counters,
+            game_settings,
+            special_seeds,
+            bestiary,
+            footer_value,
+            checksum,
+        };
+
+        // Rebirth saves do not use the same checksum as the later versions.
+        if persistent_game_data.get_isaac_version() != Some(IsaacVersion::Rebirth)
+            && checksum != get_checksum(&bytes[FILE_TAG_LENGTH..bytes.len() - CHECKSUM_LENGTH])
+        {
+            bail!("The checksum of the save file is invalid, so the file is corrupt.");
+        }
+
+        Ok(persistent_game_data)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&self.file_tag);
+        bytes.extend_from_slice(&self.header_value);
+
+        write_flag_section(&mut bytes, SECTION_ACHIEVEMENTS, &self.achievements);
+        write_counter_section(&mut bytes, SECTION_COUNTERS, &self.counters);
+        write_counter_section(&mut bytes, SECTION_LEVEL_COUNTERS, &self.level_counters);
+        write_flag_section(&mut bytes, SECTION_COLLECTIBLES, &self.collectibles);
+        write_flag_section(&mut bytes, SECTION_MINIBOSSES, &self.minibosses);
+        write_flag_section(&mut bytes, SECTION_BOSSES, &self.bosses);
+        write_flag_section(&mut bytes, SECTION_CHALLENGES, &self.challenges);
+        write_counter_section(
+            &mut bytes,
+            SECTION_CUTSCENE_COUNTERS,
+            &self.cutscene_counters,
+        );
+        write_counter_section(&mut bytes, SECTION_GAME_SETTINGS, &self.game_settings);
+        if let Some(special_seeds) = &self.special_seeds {
+            write_flag_section(&mut bytes, SECTION_SPECIAL_SEEDS, special_seeds);
+        }
+        if let Some(bestiary) = &self.bestiary {
+            write_bestiary_section(&mut bytes, bestiary);
+        }
+
+        bytes.extend_from_slice(&self.footer_value);
+        let checksum = match self.get_isaac_version() {
+            Some(IsaacVersion::Rebirth) => self.checksum,
+            _ => get_checksum(&bytes[FILE_TAG_LENGTH..]),
+        };
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+
+        bytes
+    }
+
+    /// The versions that share a file tag are told apart by the number of achievements.
+    pub fn get_isaac_version(&self) -> Option<IsaacVersion> {
+        let num_achievements = self.achievements.len().checked_sub(1)?;
+        let isaac_version = match (&self.file_tag[11..14], num_achievements) {
+            (b"06R", 178) => IsaacVersion::Rebirth,
+            (b"08R", 276) => IsaacVersion::Afterbirth,
+            (b"09R", 348) => IsaacVersion::AfterbirthPlus,
+            (b"09R", 403) => IsaacVersion::AfterbirthPlusBP5,
+            (b"09R", 637) => IsaacVersion::Repentance,
+            (b"09R", 641) => IsaacVersion::RepentancePlus,
+            _ => return None,
+        };
+
+        Some(isaac_version)
+    }
+}
+
+/// Unlike `read_persistent_game_data`, this accepts a save file for any version.
+pub fn parse_persistent_game_data(save_file_path: &Utf8Path) -> Result<PersistentGameData> {
+    let bytes = read(save_file_path).context(format!(
+        "Failed to read the file:\n{}",
+        save_file_path.to_string().green(),
+    ))?;
+
+    PersistentGameData::from_bytes(&bytes).context(InstallerError::new(
+        ErrorKind::CorruptSave,
+        format!(
+            "Failed to parse the file:\n{}",
+            save_file_path.to_string().green(),
+        ),
+    ))
+}
+
+pub fn read_persistent_game_data(
+    save_file_path: &Utf8Path,
+    isaac_version: IsaacVersion,
+) -> Result<PersistentGameData> {
+    let persistent_game_data = parse_persistent_game_data(save_file_path)?;
+
+    match persistent_game_data.get_isaac_version() {
+        Some(file_isaac_version) if file_isaac_version == isaac_version => {}
+        Some(file_isaac_version) => bail!(
+            "The following save file is for {}, but you selected {}:\n{}",
+            file_isaac_version,
+            isaac_version,
+            save_file_path.to_string().green(),
+        ),
+        None => bail!(
+            "Failed to determine which version of the game the following save file is for:\n{}",
+            save_file_path.to_string().green(),
+        ),
+    }
+
+    Ok(persistent_game_data)
+}
+
+/// Rebirth saves cannot be written, since the game would reject a file with the wrong checksum.
+pub fn write_persistent_game_data(
+    save_file_path: &Utf8Path,
+    persistent_game_data: &PersistentGameData,
+) -> Result<()> {
+    if persistent_game_data.get_isaac_version() == Some(IsaacVersion::Rebirth) {
+        bail!("Rebirth save files use a different checksum, so they cannot be changed.");
+    }
+
+    write_file(save_file_path, persistent_game_data.to_bytes())
+}
+
+struct SaveReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    sections_end: usize,
+}
+
+impl<'a> SaveReader<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self> {
+        let minimum_length =
+            FILE_TAG_LENGTH + HEADER_VALUE_LENGTH + FOOTER_VALUE_LENGTH + CHECKSUM_LENGTH;
+        if bytes.len() < minimum_length {
+            bail!("The save file is too small: {} bytes", bytes.len());
+        }
+
+        Ok(Self {
+            bytes,
+            offset: 0,
+            sections_end: bytes.len() - FOOTER_VALUE_LENGTH - CHECKSUM_LENGTH,
+        })
+    }
+
+    fn read_bytes(&mut self, length: usize) -> Result<&'a [u8]> {
+        let end = self.offset + length;
+        let slice = self.bytes.get(self.offset..end).context(format!(
+            "The save file ended early at offset {} while reading {} bytes.",
+            self.offset, length,
+        ))?;
+        self.offset = end;
+
+        Ok(slice)
+    }
+
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let slice = self.read_bytes(N)?;
+        let array = slice.try_into()?;
+
+        Ok(array)
+    }
+
+    fn read_u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.read_array()?))
+    }
+
+    fn has_more_sections(&self) -> bool {
+        self.offset + SECTION_HEADER_LENGTH <= self.sections_end
+    }
+
+    /// Returns the size and the count from the section header.
+    fn read_section_header(&mut self, section_type: u32) -> Result<(u32, u32)> {
+        let header_offset = self.offset;
+        let actual_section_type = self.read_u32()?;
+        if actual_section_type != section_type {
+            bail!(
+                "Expected section {} at offset {}, but found section {}.",
+                section_type,
+                header_offset,
+                actual_section_type,
+            );
+        }
+        let size = self.read_u32()?;
+        let count = self.read_u32()?;
+
+        Ok((size, count))
+    }
+
+    fn read_flag_section(&mut self, section_type: u32) -> Result<Vec<u8>> {
+        let (_size, count) = self.read_section_header(section_type)?;
+        let slice = self.read_bytes(count as usize)?;
+
+        Ok(slice.to_vec())
+    }
+
+    fn read_counter_section(&mut self, section_type: u32) -> Result<Vec<u32>> {
+        let (_size, count) = self.read_section_header(section_type)?;
+        let mut counters = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            counters.push(self.read_u32()?);
+        }
+
+        Ok(counters)
+    }
+
+    /// The bestiary section contains lists of entries, each with its own small header. The size
+    /// fields use 4 times the number of entries, even though each entry is 8 bytes.
+    fn read_bestiary_section(&mut self) -> Result<Bestiary> {
+        let (size, count) = self.read_section_header(SECTION_BESTIARY)?;
+
+        let mut lists = Vec::new();
+        let mut total_size = 0;
+        while self.offset < self.sections_end {
+            let list_type = self.read_u32()?;
+            let list_size = self.read_u32()?;
+            let mut entries = Vec::new();
+            for _ in 0..list_size / 4 {
+                let entity_key = self.read_u32()?;
+                let value = self.read_u32()?;
+                entries.push(BestiaryEntry { entity_key, value });
+            }
+            lists.push(BestiaryList { list_type, entries });
+            total_size += list_size;
+        }
+
+        if total_size != size {
+            bail!(
+                "The bestiary section has a size of {}, but its lists have a combined size of {}.",
+                size,
+                total_size,
+            );
+        }
+
+        Ok(Bestiary { count, lists })
+    }
+}
+
+fn write_section_header(bytes: &mut Vec<u8>, section_type: u32, size: u32, count: u32) {
+    bytes.extend_from_slice(&section_type.to_le_bytes());
+    bytes.extend_from_slice(&size.to_le_bytes());
+    bytes.extend_from_slice(&count.to_le_bytes());
+}
+
+/// The size field of the achievements section is the number of entries. Every other section uses
+/// 4 times the number of entries, even when each entry is only 1 byte.
+fn write_flag_section(bytes: &mut Vec<u8>, section_type: u32, flags: &[u8]) {
+    let count = flags.len() as u32;
+    let size = match section_type {
+        SECTION_ACHIEVEMENTS => count,
+        _ => count * 4,
+    };
+    write_section_header(bytes, section_type, size, count);
+    bytes.extend_from_slice(flags);
+}
+
+fn write_counter_section(bytes: &mut Vec<u8>, section_type: u32, counters: &[u32]) {
+    let count = counters.len() as u32;
+    write_section_header(bytes, section_type, count * 4, count);
+    for counter in counters {
+        bytes.extend_from_slice(&counter.to_le_bytes());
+    }
+}
+
+fn write_bestiary_section(bytes: &mut Vec<u8>, bestiary: &Bestiary) {
+    let size = bestiary
+        .lists
+        .iter()
+        .map(|list| list.entries.len() as u32 * 4)
+        .sum();
+    write_section_header(bytes, SECTION_BESTIARY, size, bestiary.count);
+    for list in &bestiary.lists {
+        bytes.extend_from_slice(&list.list_type.to_le_bytes());
+        bytes.extend_from_slice(&(list.entries.len() as u32 * 4).to_le_bytes());
+        for entry in &list.entries {
+            bytes.extend_from_slice(&entry.entity_key.to_le_bytes());
+            bytes.extend_from_slice(&entry.value.to_le_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::install::get_save_file_bytes;
+
+    fn get_embedded_save_files() -> Vec<(IsaacVersion, Vec<u8>)> {
+        (0..)
+            .map_while(IsaacVersion::from_repr)
+            .map(|isaac_version| (isaac_version, get_save_file_bytes(isaac_version).unwrap()))
+            .collect()
+    }
+
+    fn get_stored_checksum(bytes: &[u8]) -> u32 {
+        u32::from_le_bytes(bytes[bytes.len() - CHECKSUM_LENGTH..].try_into().unwrap())
+    }
+
+    #[test]
+    fn round_trips_every_embedded_save_file() {
+        for (isaac_version, bytes) in get_embedded_save_files() {
+            let persistent_game_data = PersistentGameData::from_bytes(&bytes).unwrap();
+            assert!(persistent_game_data.get_isaac_version() == Some(isaac_version));
+            assert!(
+                persistent_game_data.to_bytes() == bytes,
+                "{}",
+                isaac_version
+            );
+        }
+    }
+
+    #[test]
+    fn checksum_matches_the_embedded_save_files() {
+        for (isaac_version, bytes) in get_embedded_save_files() {
+            if isaac_version == IsaacVersion::Rebirth {
+                continue;
+            }
+
+            let checksum = get_checksum(&bytes[FILE_TAG_LENGTH..bytes.len() - CHECKSUM_LENGTH]);
+            assert_eq!(checksum, get_stored_checksum(&bytes), "{}", isaac_version);
+        }
+    }
+
+    #[test]
+    fn writes_a_new_checksum_after_a_change() {
+        let bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        let mut persistent_game_data = PersistentGameData::from_bytes(&bytes).unwrap();
+        persistent_game_data.counters[1] += 1;
+
+        let new_bytes = persistent_game_data.to_bytes();
+        assert_ne!(get_stored_checksum(&new_bytes), get_stored_checksum(&bytes));
+        assert!(PersistentGameData::from_bytes(&new_bytes).is_ok());
+    }
+
+    #[test]
+    fn refuses_to_write_rebirth_save_files() {
+        let bytes = get_save_file_bytes(IsaacVersion::Rebirth).unwrap();
+        let persistent_game_data = PersistentGameData::from_bytes(&bytes).unwrap();
+
+        let result = write_persistent_game_data(
+            Utf8Path::new("persistentgamedata1.dat"),
+            &persistent_game_data,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn rejects_a_corrupt_checksum() {
+        let mut bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        let last_index = bytes.len() - 1;
+        bytes[last_index] ^= 0xFF;
+
+        let err = PersistentGameData::from_bytes(&bytes).err().unwrap();
+        assert!(err.to_string().contains("checksum"));
+    }
+
+    #[test]
+    fn rejects_a_file_that_is_not_a_save_file() {
+        assert!(PersistentGameData::from_bytes(b"ISAACNGSAVE").is_err());
+
+        let mut bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        bytes[0] = b'X';
+        let err = PersistentGameData::from_bytes(&bytes).err().unwrap();
+        assert!(err.to_string().contains("ISAACNGSAVE"));
+    }
+
+    #[test]
+    fn rejects_sections_in_the_wrong_order() {
+        let mut bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        let section_type_offset = FILE_TAG_LENGTH + HEADER_VALUE_LENGTH;
+        bytes[section_type_offset..section_type_offset + 4]
+            .copy_from_slice(&SECTION_COUNTERS.to_le_bytes());
+
+        let err = PersistentGameData::from_bytes(&bytes).err().unwrap();
+        assert!(err.to_string().contains("Expected section 1"));
+    }
+
+    #[test]
+    fn parses_the_section_sizes() {
+        let bytes = get_save_file_bytes(IsaacVersion::Repentance).unwrap();
+        let persistent_game_data = PersistentGameData::from_bytes(&bytes).unwrap();
+
+        // The first achievement is at index 1, so there is one more element than there are
+        // achievements.
+        assert_eq!(persistent_game_data.achievements.len(), 638);
+        assert!(persistent_game_data.special_seeds.is_some());
+        assert!(persistent_game_data.bestiary.is_some());
+
+        let bytes = get_save_file_bytes(IsaacVersion::Rebirth).unwrap();
+        let persistent_game_data = PersistentGameData::from_bytes(&bytes).unwrap();
+        assert!(persistent_game_data.special_seeds.is_none());
+        assert!(persistent_game_data.bestiary.is_none());
+    }
+}
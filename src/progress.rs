@@ -0,0 +1,380 @@
+use crate::{
+    achievements::{AchievementTable, ACHIEVEMENTS_URL},
+    challenges::{get_challenge_ids, get_challenge_name},
+    collection::{get_collectible_ids, get_collectible_name},
+    enums::IsaacVersion,
+    errors::{ErrorKind, InstallerError},
+    install::get_save_file_bytes,
+    persistent_game_data::{read_persistent_game_data, PersistentGameData},
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+use std::ops::RangeInclusive;
+
+/// The indexes in the "counters" section that hold the completion marks, for each version. There is
+/// one mark for each character and final boss, so the number of marks is checked against the
+/// number of characters and bosses in the tests below.
+///
+/// Rebirth and Afterbirth do not store any completion marks here, and they are not needed for
+/// "Platinum God" or "Real Platinum God".
+fn get_completion_mark_counters(isaac_version: IsaacVersion) -> &'static [RangeInclusive<usize>] {
+    match isaac_version {
+        IsaacVersion::Rebirth | IsaacVersion::Afterbirth => &[],
+
+        // 14 characters with 10 marks each.
+        IsaacVersion::AfterbirthPlus => &[27..=110, 116..=157, 173..=186],
+
+        // The Forgotten adds 10 more marks.
+        IsaacVersion::AfterbirthPlusBP5 => &[27..=110, 116..=157, 173..=186, 203..=211, 213..=213],
+
+        // 34 characters (with the tainted characters) with 12 marks each, since Mother and The
+        // Beast were added.
+        IsaacVersion::Repentance | IsaacVersion::RepentancePlus => &[
+            27..=110,
+            116..=157,
+            173..=186,
+            203..=211,
+            213..=384,
+            404..=490,
+        ],
+    }
+}
+
+/// Repentance+ stores a bit for each difficulty in the completion marks, while the versions before
+/// it store the highest difficulty (1 is Normal mode and 2 is Hard mode). The fully-unlocked
+/// Repentance+ save file has all four bits set for every mark, so that is what a Hard mode mark
+/// becomes.
+const REPENTANCE_PLUS_NORMAL_MARK: u32 = 0b0001;
+const REPENTANCE_PLUS_HARD_MARK: u32 = 0b0010;
+const REPENTANCE_PLUS_FULL_MARK: u32 = 0b1111;
+
+/// Converts a completion mark from the versions before Repentance+ to Repentance+.
+pub fn get_repentance_plus_completion_mark(value: u32) -> u32 {
+    match value {
+        0 => 0,
+        1 => REPENTANCE_PLUS_NORMAL_MARK,
+        _ => REPENTANCE_PLUS_FULL_MARK,
+    }
+}
+
+/// Converts a completion mark from Repentance+ to the versions before it.
+pub fn get_repentance_completion_mark(value: u32) -> u32 {
+    if value & REPENTANCE_PLUS_HARD_MARK != 0 {
+        2
+    } else if value & REPENTANCE_PLUS_NORMAL_MARK != 0 {
+        1
+    } else {
+        0
+    }
+}
+
+pub fn progress(
+    (save_file_path, exists): &(Utf8PathBuf, bool),
+    save_file_slot: usize,
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    if !exists {
+        bail!(InstallerError::new(
+            ErrorKind::SlotEmpty,
+            format!(
+                "You cannot check the progress for slot {} since the corresponding file does not exist.",
+                save_file_slot,
+            ),
+        ));
+    }
+
+    let persistent_game_data = read_persistent_game_data(save_file_path, isaac_version)?;
+    let full_persistent_game_data =
+        PersistentGameData::from_bytes(&get_save_file_bytes(isaac_version)?)
+            .context("Failed to parse the fully-unlocked save file.")?;
+
+    let missing_achievements =
+        get_missing_achievements(&persistent_game_data, &full_persistent_game_data);
+    let missing_completion_marks = get_missing_completion_marks(
+        &persistent_game_data,
+        &full_persistent_game_data,
+        isaac_version,
+    );
+    let missing_challenges =
+        get_missing_challenges(&persistent_game_data, &full_persistent_game_data);
+    let missing_collectibles = get_missing_collectibles(&persistent_game_data, isaac_version);
+
+    let achievement_table = AchievementTable::read(isaac_version);
+    print_category(
+        "Achievements",
+        &missing_achievements,
+        |id| achievement_table.describe(id),
+        &format!(
+            "Look up the achievement on the wiki to see how to unlock it: {}",
+            ACHIEVEMENTS_URL,
+        ),
+    );
+    match get_completion_mark_counters(isaac_version).is_empty() {
+        true => {
+            println!(
+                "Completion marks: {}",
+                format!("[not needed for \"{}\"]", get_goal_name(isaac_version)).green(),
+            );
+            println!();
+        }
+        false => print_category(
+            "Completion marks",
+            &missing_completion_marks,
+            |index| format!("Completion mark (counter #{})", index),
+            "Defeat every final boss with every character on Hard mode. The marks that each character is missing are shown next to them on the character select screen.",
+        ),
+    }
+    print_category(
+        "Challenges",
+        &missing_challenges,
+        |id| format!("{}) {}", id, get_challenge_name(id)),
+        "Complete the challenge from the \"Challenges\" menu.",
+    );
+    print_category(
+        "Collection page",
+        &missing_collectibles,
+        |id| format!("{}) {}", id, get_collectible_name(id, isaac_version)),
+        "Pick up the item during a run.",
+    );
+
+    let num_missing = missing_achievements.len()
+        + missing_completion_marks.len()
+        + missing_challenges.len()
+        + missing_collectibles.len();
+    let goal = get_goal_name(isaac_version);
+    match num_missing {
+        0 => println!(
+            "This save file has everything unlocked for \"{}\".",
+            goal.green()
+        ),
+        _ => println!(
+            "This save file is missing {} thing(s) for \"{}\".",
+            num_missing.to_string().cyan(),
+            goal,
+        ),
+    }
+
+    Ok(())
+}
+
+pub fn is_completion_mark_counter(index: usize, isaac_version: IsaacVersion) -> bool {
+    get_completion_mark_counters(isaac_version)
+        .iter()
+        .any(|range| range.contains(&index))
+}
+
+fn get_missing_achievements(
+    persistent_game_data: &PersistentGameData,
+    full_persistent_game_data: &PersistentGameData,
+) -> Vec<usize> {
+    (1..full_persistent_game_data.achievements.len())
+        .filter(|id| full_persistent_game_data.achievements[*id] != 0)
+        .filter(|id| persistent_game_data.achievements.get(*id).copied() == Some(0))
+        .collect()
+}
+
+fn get_missing_completion_marks(
+    persistent_game_data: &PersistentGameData,
+    full_persistent_game_data: &PersistentGameData,
+    isaac_version: IsaacVersion,
+) -> Vec<usize> {
+    get_completion_mark_counters(isaac_version)
+        .iter()
+        .cloned()
+        .flatten()
+        .filter_map(|index| {
+            let full_value = *full_persistent_game_data.counters.get(index)?;
+            let value = persistent_game_data
+                .counters
+                .get(index)
+                .copied()
+                .unwrap_or_default();
+
+            // Each difficulty is a separate bit, so a mark is only complete when it has every bit
+            // that the fully-unlocked save file has.
+            (full_value & !value != 0).then_some(index)
+        })
+        .collect()
+}
+
+fn get_missing_challenges(
+    persistent_game_data: &PersistentGameData,
+    full_persistent_game_data: &PersistentGameData,
+) -> Vec<usize> {
+    get_challenge_ids(persistent_game_data.challenges.len())
+        .into_iter()
+        .filter(|id| full_persistent_game_data.challenges.get(*id).copied() != Some(0))
+        .filter(|id| persistent_game_data.challenges[*id] == 0)
+        .collect()
+}
+
+fn get_missing_collectibles(
+    persistent_game_data: &PersistentGameData,
+    isaac_version: IsaacVersion,
+) -> Vec<usize> {
+    get_collectible_ids(persistent_game_data.collectibles.len(), isaac_version)
+        .into_iter()
+        .filter(|id| persistent_game_data.collectibles[*id] == 0)
+        .collect()
+}
+
+fn get_goal_name(isaac_version: IsaacVersion) -> &'static str {
+    match isaac_version {
+        IsaacVersion::Rebirth => "Platinum God",
+        IsaacVersion::Afterbirth => "Real Platinum God",
+        IsaacVersion::AfterbirthPlus | IsaacVersion::AfterbirthPlusBP5 => "1001%",
+        IsaacVersion::Repentance | IsaacVersion::RepentancePlus => "Dead God",
+    }
+}
+
+fn print_category(
+    category: &str,
+    missing: &[usize],
+    describe: impl Fn(usize) -> String,
+    how_to_unlock: &str,
+) {
+    if missing.is_empty() {
+        println!("{}: {}", category, "[done]".green());
+        println!();
+        return;
+    }
+
+    println!("{}: {} missing", category, missing.len().to_string().cyan());
+    println!("How to unlock: {}", how_to_unlock);
+    for id in missing {
+        println!("- {}", describe(*id));
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        reset::get_new_persistent_game_data,
+        statistics::{get_counter_index, get_counters},
+    };
+
+    fn get_full_persistent_game_data(isaac_version: IsaacVersion) -> PersistentGameData {
+        PersistentGameData::from_bytes(&get_save_file_bytes(isaac_version).unwrap()).unwrap()
+    }
+
+    fn get_num_completion_marks(isaac_version: IsaacVersion) -> usize {
+        get_completion_mark_counters(isaac_version)
+            .iter()
+            .map(|range| range.clone().count())
+            .sum()
+    }
+
+    #[test]
+    fn has_one_completion_mark_for_each_character_and_boss() {
+        assert_eq!(get_num_completion_marks(IsaacVersion::Rebirth), 0);
+        assert_eq!(get_num_completion_marks(IsaacVersion::Afterbirth), 0);
+        assert_eq!(
+            get_num_completion_marks(IsaacVersion::AfterbirthPlus),
+            14 * 10
+        );
+        assert_eq!(
+            get_num_completion_marks(IsaacVersion::AfterbirthPlusBP5),
+            15 * 10
+        );
+        assert_eq!(get_num_completion_marks(IsaacVersion::Repentance), 34 * 12);
+        assert_eq!(
+            get_num_completion_marks(IsaacVersion::RepentancePlus),
+            34 * 12
+        );
+    }
+
+    #[test]
+    fn fully_unlocked_saves_have_every_completion_mark() {
+        for isaac_version in (0..).map_while(IsaacVersion::from_repr) {
+            let full_persistent_game_data = get_full_persistent_game_data(isaac_version);
+            for index in get_completion_mark_counters(isaac_version)
+                .iter()
+                .cloned()
+                .flatten()
+            {
+                let value = full_persistent_game_data.counters[index];
+                assert!(value != 0, "{} counter #{}", isaac_version, index);
+            }
+        }
+    }
+
+    #[test]
+    fn completion_marks_do_not_overlap_the_statistics() {
+        for isaac_version in (0..).map_while(IsaacVersion::from_repr) {
+            for counter in get_counters(isaac_version) {
+                let index = get_counter_index(counter, isaac_version).unwrap();
+                assert!(!is_completion_mark_counter(index, isaac_version));
+            }
+        }
+    }
+
+    #[test]
+    fn new_save_files_are_missing_everything() {
+        for isaac_version in (1..).map_while(IsaacVersion::from_repr) {
+            let full_persistent_game_data = get_full_persistent_game_data(isaac_version);
+            let persistent_game_data = get_new_persistent_game_data(isaac_version).unwrap();
+
+            let missing_completion_marks = get_missing_completion_marks(
+                &persistent_game_data,
+                &full_persistent_game_data,
+                isaac_version,
+            );
+            assert_eq!(
+                missing_completion_marks.len(),
+                get_num_completion_marks(isaac_version),
+            );
+
+            let missing_achievements =
+                get_missing_achievements(&persistent_game_data, &full_persistent_game_data);
+            let num_achievements = full_persistent_game_data.achievements[1..]
+                .iter()
+                .filter(|achievement| **achievement != 0)
+                .count();
+            assert_eq!(missing_achievements.len(), num_achievements);
+        }
+    }
+
+    #[test]
+    fn fully_unlocked_save_files_are_missing_nothing() {
+        for isaac_version in (0..).map_while(IsaacVersion::from_repr) {
+            let full_persistent_game_data = get_full_persistent_game_data(isaac_version);
+
+            assert!(get_missing_achievements(
+                &full_persistent_game_data,
+                &full_persistent_game_data
+            )
+            .is_empty());
+            assert!(get_missing_completion_marks(
+                &full_persistent_game_data,
+                &full_persistent_game_data,
+                isaac_version,
+            )
+            .is_empty());
+            assert!(
+                get_missing_challenges(&full_persistent_game_data, &full_persistent_game_data)
+                    .is_empty()
+            );
+        }
+    }
+
+    #[test]
+    fn hard_mode_marks_are_complete_in_repentance_plus() {
+        let full_persistent_game_data = get_full_persistent_game_data(IsaacVersion::RepentancePlus);
+        for index in get_completion_mark_counters(IsaacVersion::RepentancePlus)
+            .iter()
+            .cloned()
+            .flatten()
+        {
+            let value = full_persistent_game_data.counters[index];
+            assert_eq!(get_repentance_plus_completion_mark(2), value);
+            assert_eq!(get_repentance_completion_mark(value), 2);
+        }
+        assert_eq!(
+            get_repentance_completion_mark(get_repentance_plus_completion_mark(1)),
+            1
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    dry_run::get_success_prefix,
+    enums::{IsaacVersion, Operation},
+    get_input::confirm_reset,
+    history::{record_operation, FileState},
+    install::get_save_file_bytes,
+    persistent_game_data::{write_persistent_game_data, PersistentGameData},
+};
+use anyhow::{bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use colored::*;
+
+pub fn reset(
+    (save_file_path, exists): &(Utf8PathBuf, bool),
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    let persistent_game_data = get_new_persistent_game_data(isaac_version)?;
+
+    if *exists {
+        let confirmed = confirm_reset(save_file_path)?;
+        if !confirmed {
+            return Ok(());
+        }
+    }
+
+    reset_save_file(save_file_path, &persistent_game_data)?;
+
+    println!(
+        "{} a brand-new save file to:\n{}",
+        get_success_prefix("installed", "install"),
+        save_file_path.to_string().green(),
+    );
+
+    Ok(())
+}
+
+pub fn reset_save_file(
+    save_file_path: &Utf8Path,
+    persistent_game_data: &PersistentGameData,
+) -> Result<()> {
+    let state_before = FileState::read(save_file_path)?;
+    write_persistent_game_data(save_file_path, persistent_game_data)?;
+    record_operation(Operation::Reset, None, save_file_path, state_before)
+}
+
+/// Builds a save file with no progress. The layout is taken from the embedded save file for the
+/// version, since the file header and footer values are not understood. Rebirth is not supported,
+/// since the checksum of its save files is not known.
+pub fn get_new_persistent_game_data(isaac_version: IsaacVersion) -> Result<PersistentGameData> {
+    if isaac_version == IsaacVersion::Rebirth {
+        bail!(
+            "Rebirth save files use a different checksum, so a brand-new one cannot be generated."
+        );
+    }
+
+    let mut persistent_game_data =
+        PersistentGameData::from_bytes(&get_save_file_bytes(isaac_version)?)
+            .context("Failed to parse the embedded save file.")?;
+
+    persistent_game_data.achievements.fill(0);
+    persistent_game_data.counters.fill(0);
+    persistent_game_data.level_counters.fill(0);
+    persistent_game_data.collectibles.fill(0);
+    persistent_game_data.minibosses.fill(0);
+    persistent_game_data.bosses.fill(0);
+    persistent_game_data.challenges.fill(0);
+    persistent_game_data.cutscene_counters.fill(0);
+    if let Some(special_seeds) = &mut persistent_game_data.special_seeds {
+        special_seeds.fill(0);
+    }
+    if let Some(bestiary) = &mut persistent_game_data.bestiary {
+        for list in &mut bestiary.lists {
+            list.entries.clear();
+        }
+    }
+
+    // The "game settings" section holds options rather than progress, so it is left as-is.
+
+    Ok(persistent_game_data)
+}
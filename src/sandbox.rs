@@ -0,0 +1,81 @@
+use crate::{
+    constants::{SANDBOX_REGISTRY_FILE_NAME, SANDBOX_ROOT_ENV_VAR},
+    settings::{get_settings, update_settings},
+};
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use colored::*;
+use ini::{Ini, ParseOption};
+
+// When there is a sandbox root, every path and registry lookup is done inside of it instead of on
+// the real system. The root stands for the "C:" drive, so it holds a "Users" directory and a Steam
+// directory. The registry values are read from a "registry.ini" file in the root:
+//
+// [Software\Valve\Steam]
+// SteamPath=C:\Program Files (x86)\Steam
+//
+// [Software\Valve\Steam\ActiveProcess]
+// ActiveUser=12345
+
+/// The root from the command line takes precedence over the one from the environment variable.
+pub fn set_sandbox_root(root: Option<Utf8PathBuf>) {
+    let root = root.or_else(|| {
+        std::env::var(SANDBOX_ROOT_ENV_VAR)
+            .ok()
+            .filter(|root| !root.is_empty())
+            .map(Utf8PathBuf::from)
+    });
+    update_settings(|settings| settings.sandbox_root = root);
+}
+
+pub fn get_sandbox_root() -> Option<Utf8PathBuf> {
+    get_settings().sandbox_root
+}
+
+/// Maps a Windows path (e.g. "C:\Program Files (x86)\Steam") to the same path inside of the sandbox.
+/// The drive letter is ignored.
+pub fn get_sandbox_path(root: &Utf8Path, windows_path: &str) -> Utf8PathBuf {
+    let path = match windows_path.as_bytes() {
+        [drive_letter, b':', ..] if drive_letter.is_ascii_alphabetic() => &windows_path[2..],
+        _ => windows_path,
+    };
+
+    let mut sandbox_path = root.to_path_buf();
+    for component in path
+        .split(['\\', '/'])
+        .filter(|component| !component.is_empty())
+    {
+        sandbox_path.push(component);
+    }
+
+    sandbox_path
+}
+
+pub fn get_sandbox_registry_value(
+    root: &Utf8Path,
+    registry_path: &str,
+    value_name: &str,
+) -> Result<String> {
+    let registry_ini_path = root.join(SANDBOX_REGISTRY_FILE_NAME);
+
+    // The registry paths contain backslashes, which should not be treated as escape characters.
+    let parse_option = ParseOption {
+        enabled_quote: false,
+        enabled_escape: false,
+    };
+    let registry_ini =
+        Ini::load_from_file_opt(&registry_ini_path, parse_option).context(format!(
+            "Failed to read the sandbox registry file:\n{}",
+            registry_ini_path.to_string().green(),
+        ))?;
+
+    let value = registry_ini
+        .section(Some(registry_path))
+        .and_then(|section| section.get(value_name))
+        .context(format!(
+            "Failed to get the \"{}\" value from the sandbox registry key: {}",
+            value_name, registry_path,
+        ))?;
+
+    Ok(value.to_string())
+}
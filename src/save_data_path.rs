@@ -1,11 +1,17 @@
 use crate::{
     constants::{OPTIONS_INI, OPTIONS_SECTION_NAME, STEAM_CLOUD_NAME},
-    enums::IsaacVersion,
+    dry_run::{add_dry_run_action, get_success_prefix, is_dry_run},
+    enums::{IsaacVersion, Operation},
+    errors::{ErrorKind, InstallerError},
+    history::{record_operation_with_options_ini_change, FileState},
+    sandbox::{get_sandbox_path, get_sandbox_registry_value, get_sandbox_root},
+    settings::{get_settings, update_settings},
 };
 use anyhow::{bail, Context, Result};
 use camino::{Utf8Path, Utf8PathBuf};
 use colored::Colorize;
 use std::fs::read_to_string;
+#[cfg(windows)]
 use winreg::enums::*;
 
 const LOG_TXT: &str = "log.txt";
@@ -13,12 +19,32 @@ const STEAM_REGISTRY_PATH: &str = "Software\\Valve\\Steam";
 const STEAM_PATH_KEY_VALUE: &str = "SteamPath";
 const ACTIVE_PROCESS_REGISTRY_PATH: &str = "Software\\Valve\\Steam\\ActiveProcess";
 const ACTIVE_USER_KEY_VALUE: &str = "ActiveUser";
+#[cfg(not(windows))]
+const REGISTRY_UNAVAILABLE_MSG: &str = "The Windows registry is only available on Windows. Use a sandbox root with a \"registry.ini\" file instead.";
+
+/// Values that are used instead of searching the standard locations and the Windows registry.
+#[derive(Clone, Default)]
+pub struct SaveDataPathOverrides {
+    /// The directory that contains the "My Games" directory.
+    pub documents_dir: Option<Utf8PathBuf>,
+    /// The directory that Steam is installed in.
+    pub steam_dir: Option<Utf8PathBuf>,
+    pub steam_user_id: Option<u32>,
+}
 
-pub fn get_steam_save_data_path() -> Result<Utf8PathBuf> {
-    const ISAAC_STEAM_ID: u32 = 250900;
+pub fn set_save_data_path_overrides(save_data_path_overrides: SaveDataPathOverrides) {
+    update_settings(|settings| settings.save_data_path_overrides = save_data_path_overrides);
+}
+
+pub fn get_save_data_path_overrides() -> SaveDataPathOverrides {
+    get_settings().save_data_path_overrides
+}
 
-    let steam_installation_path = get_steam_installation_path()?;
-    let steam_user_id = get_steam_active_user_id()?;
+pub const ISAAC_STEAM_ID: u32 = 250900;
+
+pub fn get_steam_save_data_path() -> Result<Utf8PathBuf> {
+    let steam_installation_path = get_steam_dir()?;
+    let steam_user_id = get_steam_user_id()?;
 
     let steam_save_data_path = steam_installation_path
         .join("userdata")
@@ -29,7 +55,36 @@ pub fn get_steam_save_data_path() -> Result<Utf8PathBuf> {
     Ok(steam_save_data_path)
 }
 
+/// The directory that Steam is installed in.
+pub fn get_steam_dir() -> Result<Utf8PathBuf> {
+    match get_save_data_path_overrides().steam_dir {
+        Some(steam_dir) => Ok(steam_dir),
+        None => get_steam_installation_path(),
+    }
+}
+
+pub fn get_steam_user_id() -> Result<u32> {
+    match get_save_data_path_overrides().steam_user_id {
+        Some(steam_user_id) => Ok(steam_user_id),
+        None => get_steam_active_user_id(),
+    }
+}
+
 fn get_steam_installation_path() -> Result<Utf8PathBuf> {
+    if let Some(root) = get_sandbox_root() {
+        let steam_path_string =
+            get_sandbox_registry_value(&root, STEAM_REGISTRY_PATH, STEAM_PATH_KEY_VALUE)?;
+        return Ok(get_sandbox_path(&root, &steam_path_string));
+    }
+
+    let steam_path_string = get_registry_steam_path()?;
+    let steam_path = Utf8PathBuf::from(steam_path_string);
+
+    Ok(steam_path)
+}
+
+#[cfg(windows)]
+fn get_registry_steam_path() -> Result<String> {
     let hkcu = winreg::RegKey::predef(HKEY_CURRENT_USER);
     let steam_key = hkcu.open_subkey(STEAM_REGISTRY_PATH).context(format!(
         "Failed to get the Windows registry key: {}",
@@ -39,12 +94,42 @@ fn get_steam_installation_path() -> Result<Utf8PathBuf> {
         "Failed to get the \"{}\" value from the Windows registry key: {}",
         STEAM_PATH_KEY_VALUE, STEAM_REGISTRY_PATH
     ))?;
-    let steam_path = Utf8PathBuf::from(steam_path_string);
 
-    Ok(steam_path)
+    Ok(steam_path_string)
+}
+
+#[cfg(not(windows))]
+fn get_registry_steam_path() -> Result<String> {
+    bail!(REGISTRY_UNAVAILABLE_MSG)
 }
 
 fn get_steam_active_user_id() -> Result<u32> {
+    let active_user = match get_sandbox_root() {
+        Some(root) => {
+            let active_user_string = get_sandbox_registry_value(
+                &root,
+                ACTIVE_PROCESS_REGISTRY_PATH,
+                ACTIVE_USER_KEY_VALUE,
+            )?;
+            active_user_string.parse().context(format!(
+                "Failed to convert the \"{}\" value to a number: {}",
+                ACTIVE_USER_KEY_VALUE, active_user_string,
+            ))?
+        }
+        None => get_registry_active_user()?,
+    };
+
+    match active_user {
+        0 => bail!(InstallerError::new(
+            ErrorKind::SteamNotLoggedIn,
+            "You are not currently logged into Steam.",
+        )),
+        _ => Ok(active_user),
+    }
+}
+
+#[cfg(windows)]
+fn get_registry_active_user() -> Result<u32> {
     let hkcu = winreg::RegKey::predef(HKEY_CURRENT_USER);
     let active_process_key = hkcu
         .open_subkey(ACTIVE_PROCESS_REGISTRY_PATH)
@@ -59,16 +144,36 @@ fn get_steam_active_user_id() -> Result<u32> {
             ACTIVE_USER_KEY_VALUE, ACTIVE_PROCESS_REGISTRY_PATH
         ))?;
 
-    match active_user {
-        0 => bail!("You are not currently logged into Steam. Please make sure that Steam is open and that you are logged in."),
-        _ => Ok(active_user),
-    }
+    Ok(active_user)
+}
+
+#[cfg(not(windows))]
+fn get_registry_active_user() -> Result<u32> {
+    bail!(REGISTRY_UNAVAILABLE_MSG)
 }
 
 pub fn get_documents_save_data_path(isaac_version: IsaacVersion) -> Result<Utf8PathBuf> {
     let username = get_username();
     let version_directory_name = get_version_directory_name(isaac_version);
 
+    if let Some(documents_dir) = get_save_data_path_overrides().documents_dir {
+        let path = documents_dir.join("My Games").join(version_directory_name);
+        if !path.is_dir() {
+            bail!(InstallerError::new(
+                ErrorKind::SaveDirNotFound,
+                format!(
+                    "Failed to find your documents save data directory at:\n{}",
+                    path.to_string().green(),
+                ),
+            ));
+        }
+        return Ok(path);
+    }
+
+    if let Some(root) = get_sandbox_root() {
+        return get_documents_save_data_path_sandbox(&root, &username, &version_directory_name);
+    }
+
     // If the user has a custom "Documents" directory, Isaac ignores this and instead puts its files
     // in the standard location. Test to see if the "log.txt" file exists at the "standard"
     // location. (e.g. "C:\Users\Alice\Documents\My Games\Binding of Isaac Repentance\log.txt")
@@ -115,10 +220,56 @@ pub fn get_documents_save_data_path(isaac_version: IsaacVersion) -> Result<Utf8P
         "C:\\Users\\{}\\Documents\\My Games\\{}\\log.txt",
         username, version_directory_name
     );
-    bail!(
-        "Failed to find your documents save data directory at:\n{}\n\nDo you have the selected version of the game installed? If you do, try opening the game, closing the game, and then retrying (so that the \"log.txt\" file is created).",
-        default_log_txt_path.green(),
-    )
+    bail!(InstallerError::new(
+        ErrorKind::SaveDirNotFound,
+        format!(
+            "Failed to find your documents save data directory at:\n{}",
+            default_log_txt_path.green(),
+        ),
+    ))
+}
+
+/// The sandbox might hold a copy of another user's files, so every user directory is searched.
+fn get_documents_save_data_path_sandbox(
+    root: &Utf8Path,
+    username: &str,
+    version_directory_name: &str,
+) -> Result<Utf8PathBuf> {
+    let users_path = root.join("Users");
+    let mut user_paths: Vec<Utf8PathBuf> = users_path
+        .read_dir_utf8()
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.into_path())
+        .collect();
+    user_paths.sort();
+
+    for user_path in &user_paths {
+        for documents_path in [
+            user_path.join("Documents"),
+            user_path.join("OneDrive").join("Documents"),
+        ] {
+            let path = documents_path.join("My Games").join(version_directory_name);
+            if path.join(LOG_TXT).exists() {
+                return Ok(path);
+            }
+        }
+    }
+
+    let default_log_txt_path = users_path
+        .join(username)
+        .join("Documents")
+        .join("My Games")
+        .join(version_directory_name)
+        .join(LOG_TXT);
+    bail!(InstallerError::new(
+        ErrorKind::SaveDirNotFound,
+        format!(
+            "Failed to find your documents save data directory in the sandbox at:\n{}",
+            default_log_txt_path.to_string().green(),
+        ),
+    ))
 }
 
 fn get_documents_save_data_path_standard(
@@ -201,26 +352,37 @@ fn get_version_directory_name(isaac_version: IsaacVersion) -> String {
 pub fn get_steam_cloud_enabled(documents_save_data_path: &Utf8Path) -> Result<bool> {
     let options_ini = get_options_ini(documents_save_data_path)?;
 
-    let options_section = options_ini
-        .section(Some(OPTIONS_SECTION_NAME))
-        .context(format!(
-            "The \"{}\" file does not have a section called: {}",
-            OPTIONS_INI, OPTIONS_SECTION_NAME,
+    let options_section =
+        options_ini
+            .section(Some(OPTIONS_SECTION_NAME))
+            .context(InstallerError::new(
+                ErrorKind::OptionsIni,
+                format!(
+                    "The \"{}\" file does not have a section called: {}",
+                    OPTIONS_INI, OPTIONS_SECTION_NAME,
+                ),
+            ))?;
+
+    let steam_cloud_string = options_section
+        .get(STEAM_CLOUD_NAME)
+        .context(InstallerError::new(
+            ErrorKind::OptionsIni,
+            format!(
+                "The \"{}\" file does not have a key called: {}",
+                OPTIONS_INI, STEAM_CLOUD_NAME,
+            ),
         ))?;
 
-    let steam_cloud_string = options_section.get(STEAM_CLOUD_NAME).context(format!(
-        "The \"{}\" file does not have a key called: {}",
-        OPTIONS_INI, STEAM_CLOUD_NAME,
-    ))?;
-
     match steam_cloud_string {
         "0" => Ok(false),
         "1" => Ok(true),
-        _ => bail!(
-            "The value for the \"{}\" key is invalid: {}",
-            STEAM_CLOUD_NAME,
-            steam_cloud_string,
-        ),
+        _ => bail!(InstallerError::new(
+            ErrorKind::OptionsIni,
+            format!(
+                "The value for the \"{}\" key is invalid: {}",
+                STEAM_CLOUD_NAME, steam_cloud_string,
+            ),
+        )),
     }
 }
 
@@ -232,9 +394,12 @@ fn get_options_ini(documents_save_data_path: &Utf8Path) -> Result<ini::Ini> {
         options_ini_path.to_string().green(),
     ))?;
 
-    let options_ini = ini::Ini::load_from_str(&options_ini_string).context(format!(
-        "Failed to parse the file:\n{}",
-        options_ini_path.to_string().green(),
+    let options_ini = ini::Ini::load_from_str(&options_ini_string).context(InstallerError::new(
+        ErrorKind::OptionsIni,
+        format!(
+            "Failed to parse the file:\n{}",
+            options_ini_path.to_string().green(),
+        ),
     ))?;
 
     Ok(options_ini)
@@ -244,11 +409,14 @@ fn get_options_ini_path(documents_save_data_path: &Utf8Path) -> Result<Utf8PathB
     let options_ini_path = documents_save_data_path.join(OPTIONS_INI);
 
     if !options_ini_path.exists() {
-        bail!(
-            "Failed to find your \"{}\" file at \":\n{}",
-            OPTIONS_INI,
-            options_ini_path.to_string().green(),
-        );
+        bail!(InstallerError::new(
+            ErrorKind::OptionsIni,
+            format!(
+                "Failed to find your \"{}\" file at:\n{}",
+                OPTIONS_INI,
+                options_ini_path.to_string().green(),
+            ),
+        ));
     }
 
     Ok(options_ini_path)
@@ -267,10 +435,34 @@ pub fn toggle_steam_cloud_enabled(
     options_section.set(STEAM_CLOUD_NAME, toggled_setting);
 
     let options_ini_path = get_options_ini_path(documents_save_data_path)?;
-    options_ini.write_to_file(&options_ini_path)?;
+    let options_ini_change = format!(
+        "{}={} --> {}={}",
+        STEAM_CLOUD_NAME,
+        u8::from(previously_enabled),
+        STEAM_CLOUD_NAME,
+        toggled_setting,
+    );
+    let state_before = FileState::read(&options_ini_path)?;
+    if is_dry_run() {
+        add_dry_run_action(format!(
+            "Change \"{}\" in:\n{}",
+            options_ini_change,
+            options_ini_path.to_string().green(),
+        ));
+    } else {
+        options_ini.write_to_file(&options_ini_path)?;
+    }
+    record_operation_with_options_ini_change(
+        Operation::ChangeSteamCloud,
+        None,
+        &options_ini_path,
+        state_before,
+        Some(options_ini_change),
+    )?;
 
     println!(
-        "Successfully set the \"{}\" value to \"{}\" in the following file:\n{}",
+        "{} the \"{}\" value to \"{}\" in the following file:\n{}",
+        get_success_prefix("set", "set"),
         STEAM_CLOUD_NAME,
         toggled_setting,
         options_ini_path.to_string().green(),
@@ -1,16 +1,170 @@
+use crate::enums::IsaacVersion;
+use anyhow::{Context, Result};
+use flate2::read::DeflateDecoder;
+use std::io::Read;
+
+// The uncompressed save files are only used to check the registry when building, so they do not end
+// up in the program.
 pub const SAVE_FILE_REBIRTH: &[u8] = include_bytes!("../saves/Rebirth/persistentgamedata.dat");
 
 pub const SAVE_FILE_AFTERBIRTH: &[u8] =
     include_bytes!("../saves/Afterbirth/persistentgamedata.dat");
 
 pub const SAVE_FILE_AFTERBIRTH_PLUS: &[u8] =
-    include_bytes!("../saves/Afterbirth+BP5/persistentgamedata.dat");
+    include_bytes!("../saves/Afterbirth+/persistentgamedata.dat");
 
 pub const SAVE_FILE_AFTERBIRTH_PLUS_BP5: &[u8] =
-    include_bytes!("../saves/Afterbirth+/persistentgamedata.dat");
+    include_bytes!("../saves/Afterbirth+BP5/persistentgamedata.dat");
 
 pub const SAVE_FILE_REPENTANCE: &[u8] =
     include_bytes!("../saves/Repentance/persistentgamedata.dat");
 
 pub const SAVE_FILE_REPENTANCE_PLUS: &[u8] =
     include_bytes!("../saves/Repentance+/persistentgamedata.dat");
+
+const SAVE_FILES: [&[u8]; 6] = [
+    SAVE_FILE_REBIRTH,
+    SAVE_FILE_AFTERBIRTH,
+    SAVE_FILE_AFTERBIRTH_PLUS,
+    SAVE_FILE_AFTERBIRTH_PLUS_BP5,
+    SAVE_FILE_REPENTANCE,
+    SAVE_FILE_REPENTANCE_PLUS,
+];
+
+/// A save file that is built into the program, along with what it is expected to contain.
+pub struct EmbeddedSaveFile {
+    pub isaac_version: IsaacVersion,
+    pub file_tag: &'static [u8; 16],
+    pub size: usize,
+    /// The number of achievements in the game. (This does not include the unused element 0.)
+    pub num_achievements: usize,
+    /// Compressed with Deflate by the build script.
+    pub compressed_bytes: &'static [u8],
+}
+
+/// Indexed by `IsaacVersion`.
+pub const EMBEDDED_SAVE_FILES: [EmbeddedSaveFile; 6] = [
+    EmbeddedSaveFile {
+        isaac_version: IsaacVersion::Rebirth,
+        file_tag: b"ISAACNGSAVE06R  ",
+        size: 1259,
+        num_achievements: 178,
+        compressed_bytes: include_bytes!(concat!(env!("OUT_DIR"), "/Rebirth.dat.deflate")),
+    },
+    EmbeddedSaveFile {
+        isaac_version: IsaacVersion::Afterbirth,
+        file_tag: b"ISAACNGSAVE08R  ",
+        size: 1837,
+        num_achievements: 276,
+        compressed_bytes: include_bytes!(concat!(env!("OUT_DIR"), "/Afterbirth.dat.deflate")),
+    },
+    EmbeddedSaveFile {
+        isaac_version: IsaacVersion::AfterbirthPlus,
+        file_tag: b"ISAACNGSAVE09R  ",
+        size: 8352,
+        num_achievements: 348,
+        compressed_bytes: include_bytes!(concat!(env!("OUT_DIR"), "/Afterbirth+.dat.deflate")),
+    },
+    EmbeddedSaveFile {
+        isaac_version: IsaacVersion::AfterbirthPlusBP5,
+        file_tag: b"ISAACNGSAVE09R  ",
+        size: 8750,
+        num_achievements: 403,
+        compressed_bytes: include_bytes!(concat!(env!("OUT_DIR"), "/Afterbirth+BP5.dat.deflate")),
+    },
+    EmbeddedSaveFile {
+        isaac_version: IsaacVersion::Repentance,
+        file_tag: b"ISAACNGSAVE09R  ",
+        size: 17924,
+        num_achievements: 637,
+        compressed_bytes: include_bytes!(concat!(env!("OUT_DIR"), "/Repentance.dat.deflate")),
+    },
+    EmbeddedSaveFile {
+        isaac_version: IsaacVersion::RepentancePlus,
+        file_tag: b"ISAACNGSAVE09R  ",
+        size: 18036,
+        num_achievements: 641,
+        compressed_bytes: include_bytes!(concat!(env!("OUT_DIR"), "/Repentance+.dat.deflate")),
+    },
+];
+
+// Fail the build if an embedded save file does not match the version that it is registered for.
+const _: () = check_embedded_save_files();
+
+pub fn get_embedded_save_file(isaac_version: IsaacVersion) -> &'static EmbeddedSaveFile {
+    &EMBEDDED_SAVE_FILES[isaac_version as usize]
+}
+
+impl EmbeddedSaveFile {
+    pub fn decompress(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(self.size);
+        DeflateDecoder::new(self.compressed_bytes)
+            .read_to_end(&mut bytes)
+            .context(format!(
+                "Failed to decompress the embedded save file for {}.",
+                self.isaac_version,
+            ))?;
+
+        Ok(bytes)
+    }
+}
+
+const fn check_embedded_save_files() {
+    let mut i = 0;
+    while i < EMBEDDED_SAVE_FILES.len() {
+        let embedded_save_file = &EMBEDDED_SAVE_FILES[i];
+        let bytes = SAVE_FILES[i];
+
+        if embedded_save_file.isaac_version as usize != i {
+            panic!("An embedded save file is registered in the wrong position.");
+        }
+        if bytes.len() != embedded_save_file.size {
+            panic!("An embedded save file does not have the expected size.");
+        }
+
+        let mut j = 0;
+        while j < embedded_save_file.file_tag.len() {
+            if bytes[j] != embedded_save_file.file_tag[j] {
+                panic!("An embedded save file does not have the expected file tag.");
+            }
+            j += 1;
+        }
+
+        // The achievements section comes first, after the 16 byte file tag, a 4 byte value, and the
+        // section type and size.
+        let num_achievements = read_u32(bytes, 28) as usize;
+        if num_achievements != embedded_save_file.num_achievements + 1 {
+            panic!("An embedded save file does not have the expected number of achievements.");
+        }
+
+        i += 1;
+    }
+}
+
+const fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn decompressed_save_files_match_source_files() {
+        for (embedded_save_file, bytes) in EMBEDDED_SAVE_FILES.iter().zip(SAVE_FILES) {
+            let decompressed_bytes = embedded_save_file.decompress().unwrap();
+            assert_eq!(
+                Sha256::digest(&decompressed_bytes),
+                Sha256::digest(bytes),
+                "The embedded save file for {} does not match its source file.",
+                embedded_save_file.isaac_version,
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    config::{read_config, write_config},
+    dry_run::get_success_prefix,
+    get_input::{prompt_for_documents_dir, prompt_for_steam_dir, prompt_for_steam_user_id},
+    save_data_path::{get_save_data_path_overrides, set_save_data_path_overrides},
+};
+use anyhow::{bail, Result};
+use camino::Utf8Path;
+use colored::*;
+
+/// The paths that are currently used are shown, which includes the ones from the command line.
+pub fn set_up_paths() -> Result<()> {
+    let mut config = read_config()?;
+    let overrides = get_save_data_path_overrides();
+
+    let documents_dir = prompt_for_documents_dir(overrides.documents_dir.as_deref())?;
+    if let Some(documents_dir) = &documents_dir {
+        check_dir_exists(&documents_dir.join("My Games"))?;
+    }
+
+    let steam_dir = prompt_for_steam_dir(overrides.steam_dir.as_deref())?;
+    if let Some(steam_dir) = &steam_dir {
+        check_dir_exists(&steam_dir.join("userdata"))?;
+    }
+
+    let steam_user_id = prompt_for_steam_user_id(overrides.steam_user_id)?;
+    if let (Some(steam_dir), Some(steam_user_id)) = (&steam_dir, steam_user_id) {
+        check_dir_exists(&steam_dir.join("userdata").join(steam_user_id.to_string()))?;
+    }
+
+    config.documents_dir = documents_dir;
+    config.steam_dir = steam_dir;
+    config.steam_user_id = steam_user_id;
+    write_config(&config)?;
+    set_save_data_path_overrides(config.get_save_data_path_overrides());
+
+    println!(
+        "{}",
+        format!("{} the paths.", get_success_prefix("saved", "save")).green(),
+    );
+
+    Ok(())
+}
+
+fn check_dir_exists(path: &Utf8Path) -> Result<()> {
+    if !path.is_dir() {
+        bail!(
+            "Failed to find the following directory:\n{}",
+            path.to_string().green(),
+        );
+    }
+
+    Ok(())
+}
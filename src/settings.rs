@@ -0,0 +1,25 @@
+use crate::save_data_path::SaveDataPathOverrides;
+use camino::Utf8PathBuf;
+use std::sync::{LazyLock, PoisonError, RwLock};
+
+/// The settings that apply to the whole run of the installer. They are kept in one place, so that
+/// every thread sees the same settings.
+#[derive(Clone, Default)]
+pub struct Settings {
+    pub dry_run: bool,
+    pub sandbox_root: Option<Utf8PathBuf>,
+    pub save_data_path_overrides: SaveDataPathOverrides,
+}
+
+static SETTINGS: LazyLock<RwLock<Settings>> = LazyLock::new(RwLock::default);
+
+pub fn get_settings() -> Settings {
+    SETTINGS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+pub fn update_settings(update: impl FnOnce(&mut Settings)) {
+    update(&mut SETTINGS.write().unwrap_or_else(PoisonError::into_inner));
+}
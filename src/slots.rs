@@ -0,0 +1,137 @@
+use crate::{
+    backup::backup_before_overwrite,
+    dry_run::{copy_file, delete_file, get_success_prefix, write_file},
+    enums::{Operation, SlotAction},
+    errors::{ErrorKind, InstallerError},
+    get_input::{
+        confirm_overwrite_save_file, prompt_for_destination_save_file_slot, prompt_for_slot_action,
+        prompt_for_source_save_file_slot,
+    },
+    history::{record_operation, FileState},
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+use std::fs::read;
+
+pub fn manage_slots(existing_save_files: &[(Utf8PathBuf, bool)]) -> Result<()> {
+    let slot_action = prompt_for_slot_action()?;
+    let source_slot = prompt_for_source_save_file_slot(slot_action)?;
+    let destination_slot = prompt_for_destination_save_file_slot(slot_action)?;
+    if source_slot == destination_slot {
+        bail!("You must choose two different slots.");
+    }
+
+    let (source_path, source_exists) = get_save_file(source_slot, existing_save_files)?;
+    let (destination_path, destination_exists) =
+        get_save_file(destination_slot, existing_save_files)?;
+
+    if !source_exists && (slot_action != SlotAction::Swap || !destination_exists) {
+        bail!(InstallerError::new(
+            ErrorKind::SlotEmpty,
+            format!(
+                "You cannot {} the save file for slot {} since the corresponding file does not exist.",
+                slot_action,
+                source_slot,
+            ),
+        ));
+    }
+
+    // Swapping into an empty slot only moves the destination file, so nothing is overwritten.
+    let destination_overwritten =
+        *destination_exists && (slot_action != SlotAction::Swap || *source_exists);
+    if destination_overwritten {
+        let confirmed = confirm_overwrite_save_file(destination_path)?;
+        if !confirmed {
+            return Ok(());
+        }
+        backup_before_overwrite(destination_path)?;
+    }
+    if slot_action == SlotAction::Swap && *source_exists && *destination_exists {
+        backup_before_overwrite(source_path)?;
+    }
+
+    match slot_action {
+        SlotAction::Copy => {
+            let state_before = FileState::read(destination_path)?;
+            copy_save_file(source_path, destination_path)?;
+            record_operation(
+                Operation::Copy,
+                Some(source_path),
+                destination_path,
+                state_before,
+            )?;
+        }
+        SlotAction::Move => move_save_file(source_path, destination_path)?,
+        SlotAction::Swap => match (source_exists, destination_exists) {
+            (true, true) => swap_save_files(source_path, destination_path)?,
+            (true, false) => move_save_file(source_path, destination_path)?,
+            (false, true) => move_save_file(destination_path, source_path)?,
+            (false, false) => unreachable!(),
+        },
+    }
+
+    println!(
+        "{} slot {} {} slot {}.",
+        match slot_action {
+            SlotAction::Copy => get_success_prefix("copied", "copy"),
+            SlotAction::Move => get_success_prefix("moved", "move"),
+            SlotAction::Swap => get_success_prefix("swapped", "swap"),
+        },
+        source_slot,
+        match slot_action {
+            SlotAction::Copy | SlotAction::Move => "to",
+            SlotAction::Swap => "with",
+        },
+        destination_slot,
+    );
+
+    Ok(())
+}
+
+fn get_save_file(
+    save_file_slot: usize,
+    existing_save_files: &[(Utf8PathBuf, bool)],
+) -> Result<&(Utf8PathBuf, bool)> {
+    let save_file_index = save_file_slot - 1;
+    existing_save_files.get(save_file_index).context(format!(
+        "Failed to get the save file at index: {}",
+        save_file_index
+    ))
+}
+
+fn copy_save_file(source_path: &Utf8PathBuf, destination_path: &Utf8PathBuf) -> Result<()> {
+    copy_file(source_path, destination_path)
+}
+
+fn move_save_file(source_path: &Utf8PathBuf, destination_path: &Utf8PathBuf) -> Result<()> {
+    let state_before = FileState::read(destination_path)?;
+    copy_save_file(source_path, destination_path)?;
+    delete_file(source_path)?;
+    record_operation(
+        Operation::Move,
+        Some(source_path),
+        destination_path,
+        state_before,
+    )
+}
+
+fn swap_save_files(path_a: &Utf8PathBuf, path_b: &Utf8PathBuf) -> Result<()> {
+    let bytes_a = read(path_a).context(format!(
+        "Failed to read the file:\n{}",
+        path_a.to_string().green(),
+    ))?;
+    let bytes_b = read(path_b).context(format!(
+        "Failed to read the file:\n{}",
+        path_b.to_string().green(),
+    ))?;
+
+    let state_before_a = FileState::read(path_a)?;
+    let state_before_b = FileState::read(path_b)?;
+    write_file(path_a, bytes_b)?;
+    write_file(path_b, bytes_a)?;
+
+    // Each of the two files is changed, so each one gets its own entry.
+    record_operation(Operation::Swap, Some(path_b), path_a, state_before_a)?;
+    record_operation(Operation::Swap, Some(path_a), path_b, state_before_b)
+}
@@ -0,0 +1,227 @@
+use crate::{
+    dry_run::get_success_prefix,
+    enums::{Counter, IsaacVersion, Operation},
+    errors::{ErrorKind, InstallerError},
+    get_input::{confirm_edit_counter, prompt_for_counter, prompt_for_counter_value},
+    history::{record_operation, FileState},
+    persistent_game_data::{
+        read_persistent_game_data, write_persistent_game_data, PersistentGameData,
+    },
+};
+use anyhow::{bail, Context, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+use strum::IntoEnumIterator;
+
+pub fn statistics(
+    (save_file_path, exists): &(Utf8PathBuf, bool),
+    save_file_slot: usize,
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    if !exists {
+        bail!(InstallerError::new(
+            ErrorKind::SlotEmpty,
+            format!(
+                "You cannot view the statistics for slot {} since the corresponding file does not exist.",
+                save_file_slot,
+            ),
+        ));
+    }
+
+    let mut persistent_game_data = read_persistent_game_data(save_file_path, isaac_version)?;
+    let counters = get_counters(isaac_version);
+    print_counters(&persistent_game_data, &counters, isaac_version)?;
+
+    let confirm = confirm_edit_counter()?;
+    if !confirm {
+        return Ok(());
+    }
+
+    let counter = prompt_for_counter(&counters)?;
+    let value = prompt_for_counter_value(counter)?;
+    let index = get_counter_index(counter, isaac_version)
+        .context(format!("The \"{}\" counter does not exist.", counter))?;
+    let old_value = get_counter_value(&persistent_game_data, index)?;
+    persistent_game_data.counters[index] = get_stored_value(counter, value)?;
+    let state_before = FileState::read(save_file_path)?;
+    write_persistent_game_data(save_file_path, &persistent_game_data)?;
+    record_operation(Operation::Edit, None, save_file_path, state_before)?;
+
+    println!(
+        "{} \"{}\" from {} to {} in:\n{}",
+        get_success_prefix("changed", "change"),
+        counter,
+        get_display_value(counter, old_value),
+        value.to_string().green(),
+        save_file_path.to_string().green(),
+    );
+
+    Ok(())
+}
+
+/// Returns the counters that exist in the save files for the given version.
+pub fn get_counters(isaac_version: IsaacVersion) -> Vec<Counter> {
+    Counter::iter()
+        .filter(|counter| get_counter_index(*counter, isaac_version).is_some())
+        .collect()
+}
+
+/// Returns the index into the "counters" section of the save file.
+///
+/// The save file format is not documented by the developers, so there is no official source for
+/// these indexes. The ones that can be checked are checked against the embedded save files in the
+/// tests below: the fully-unlocked saves have donated at least 900 coins to the donation machine
+/// (the Afterbirth+ save stops at 938), have at least as many Mom kills as wins, store a small win
+/// streak (see `WIN_STREAK_OFFSET`), and (after Rebirth) have donated at least 999 coins to the
+/// Greed donation machine.
+pub fn get_counter_index(counter: Counter, isaac_version: IsaacVersion) -> Option<usize> {
+    let index = match counter {
+        Counter::MomKills => 1,
+        Counter::RocksDestroyed => 2,
+        Counter::TintedRocksDestroyed => 3,
+        Counter::PoopsDestroyed => 5,
+        Counter::PillsEaten => 6,
+        Counter::Wins => 9,
+        Counter::Deaths => 12,
+        Counter::ShopkeepersKilled => 14,
+        Counter::EdenTokens => 19,
+        Counter::DonationMachine => 20,
+        Counter::WinStreak => 21,
+
+        // Greed Mode was added in Afterbirth, and the counter moved in Afterbirth+.
+        Counter::GreedDonationMachine => match isaac_version {
+            IsaacVersion::Rebirth => return None,
+            IsaacVersion::Afterbirth => 108,
+            IsaacVersion::AfterbirthPlus
+            | IsaacVersion::AfterbirthPlusBP5
+            | IsaacVersion::Repentance
+            | IsaacVersion::RepentancePlus => 115,
+        },
+    };
+
+    Some(index)
+}
+
+/// The game stores the win streak with an offset, so that it never goes below 0. Every embedded save
+/// file that has finished a run stores a value close to this one (e.g. 10,000,004 for a streak of 4),
+/// and a save file that has never finished a run stores 0.
+const WIN_STREAK_OFFSET: u32 = 10_000_000;
+
+/// Returns the value that the game shows for the stored value of the counter.
+pub fn get_display_value(counter: Counter, stored_value: u32) -> i64 {
+    match counter {
+        Counter::WinStreak if stored_value != 0 => {
+            i64::from(stored_value) - i64::from(WIN_STREAK_OFFSET)
+        }
+        _ => i64::from(stored_value),
+    }
+}
+
+fn get_stored_value(counter: Counter, value: u32) -> Result<u32> {
+    match counter {
+        Counter::WinStreak => value
+            .checked_add(WIN_STREAK_OFFSET)
+            .filter(|stored_value| *stored_value <= i32::MAX as u32)
+            .context(format!("The value for \"{}\" is too large.", counter)),
+        _ => Ok(value),
+    }
+}
+
+fn get_counter_value(persistent_game_data: &PersistentGameData, index: usize) -> Result<u32> {
+    persistent_game_data
+        .counters
+        .get(index)
+        .copied()
+        .context(format!(
+            "The save file only has {} counters, so it does not have a counter at index: {}",
+            persistent_game_data.counters.len(),
+            index,
+        ))
+}
+
+fn print_counters(
+    persistent_game_data: &PersistentGameData,
+    counters: &[Counter],
+    isaac_version: IsaacVersion,
+) -> Result<()> {
+    println!("The statistics for this save file are as follows:");
+    for counter in counters {
+        let index = get_counter_index(*counter, isaac_version)
+            .context(format!("The \"{}\" counter does not exist.", counter))?;
+        let value = get_counter_value(persistent_game_data, index)?;
+        println!(
+            "- {}: {}",
+            counter,
+            get_display_value(*counter, value).to_string().green(),
+        );
+    }
+    println!();
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::install::get_save_file_bytes;
+
+    fn get_embedded_counter(isaac_version: IsaacVersion, counter: Counter) -> u32 {
+        let persistent_game_data =
+            PersistentGameData::from_bytes(&get_save_file_bytes(isaac_version).unwrap()).unwrap();
+        let index = get_counter_index(counter, isaac_version).unwrap();
+        get_counter_value(&persistent_game_data, index).unwrap()
+    }
+
+    #[test]
+    fn every_counter_is_in_the_embedded_save_files() {
+        for isaac_version in (0..).map_while(IsaacVersion::from_repr) {
+            for counter in get_counters(isaac_version) {
+                get_embedded_counter(isaac_version, counter);
+            }
+        }
+    }
+
+    #[test]
+    fn greed_donation_machine_only_exists_after_rebirth() {
+        assert!(!get_counters(IsaacVersion::Rebirth).contains(&Counter::GreedDonationMachine));
+        assert!(get_counters(IsaacVersion::Afterbirth).contains(&Counter::GreedDonationMachine));
+    }
+
+    #[test]
+    fn fully_unlocked_saves_have_the_donation_machine_unlocks() {
+        for isaac_version in (0..).map_while(IsaacVersion::from_repr) {
+            // The Afterbirth+ save stops at 938.
+            assert!(get_embedded_counter(isaac_version, Counter::DonationMachine) >= 900);
+            if isaac_version != IsaacVersion::Rebirth {
+                assert!(get_embedded_counter(isaac_version, Counter::GreedDonationMachine) >= 999);
+            }
+        }
+    }
+
+    #[test]
+    fn fully_unlocked_saves_have_a_small_win_streak() {
+        for isaac_version in (0..).map_while(IsaacVersion::from_repr) {
+            let stored_value = get_embedded_counter(isaac_version, Counter::WinStreak);
+            let win_streak = get_display_value(Counter::WinStreak, stored_value);
+            assert!((0..100).contains(&win_streak), "{}", win_streak);
+        }
+    }
+
+    #[test]
+    fn stores_the_win_streak_with_the_offset() {
+        let stored_value = get_stored_value(Counter::WinStreak, 4).unwrap();
+        assert_eq!(stored_value, 10_000_004);
+        assert_eq!(get_display_value(Counter::WinStreak, stored_value), 4);
+        assert_eq!(get_stored_value(Counter::Deaths, 4).unwrap(), 4);
+        assert!(get_stored_value(Counter::WinStreak, i32::MAX as u32).is_err());
+    }
+
+    #[test]
+    fn wins_are_not_more_than_mom_kills() {
+        for isaac_version in (0..).map_while(IsaacVersion::from_repr) {
+            let wins = get_embedded_counter(isaac_version, Counter::Wins);
+            assert!(wins > 0);
+            assert!(wins <= get_embedded_counter(isaac_version, Counter::MomKills));
+        }
+    }
+}
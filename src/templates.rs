@@ -0,0 +1,220 @@
+use crate::{
+    config::read_config,
+    constants::{
+        SAVE_TEMPLATES_DIR_ENV_VAR, SAVE_TEMPLATES_DIR_NAME, SAVE_TEMPLATE_FILE_NAME,
+        SAVE_TEMPLATE_MANIFEST,
+    },
+    enums::IsaacVersion,
+    utils::get_dir_of_running_exe,
+};
+use anyhow::{bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use colored::*;
+use serde::Deserialize;
+use std::fs::read_to_string;
+
+/// A save file that is not built into the program. Each template is a directory that contains a
+/// "manifest.json" file and a "persistentgamedata.dat" file.
+pub struct SaveTemplate {
+    pub name: String,
+    pub isaac_version: IsaacVersion,
+    pub description: String,
+    pub save_file_path: Utf8PathBuf,
+}
+
+#[derive(Deserialize)]
+struct Manifest {
+    name: String,
+    version: IsaacVersion,
+    #[serde(default)]
+    description: String,
+}
+
+/// Looks for templates in the "saves" directory next to this program and in the templates directory
+/// from the config file or the "ISAAC_SAVE_TEMPLATES_DIR" environment variable. Templates that cannot
+/// be read are skipped with a warning.
+pub fn get_save_templates(isaac_version: IsaacVersion) -> Result<Vec<SaveTemplate>> {
+    let mut templates_dir_paths = vec![get_dir_of_running_exe()?.join(SAVE_TEMPLATES_DIR_NAME)];
+    if let Some(templates_dir) = get_templates_dir()? {
+        templates_dir_paths.push(templates_dir);
+    }
+
+    let mut save_templates = Vec::new();
+    for templates_dir_path in templates_dir_paths {
+        let Ok(entries) = templates_dir_path.read_dir_utf8() else {
+            continue;
+        };
+
+        for entry in entries.flatten() {
+            let template_dir_path = entry.path();
+            if !template_dir_path.join(SAVE_TEMPLATE_MANIFEST).exists() {
+                continue;
+            }
+
+            match read_save_template(template_dir_path) {
+                Ok(save_template) => {
+                    if save_template.isaac_version == isaac_version {
+                        save_templates.push(save_template);
+                    }
+                }
+                Err(err) => println!(
+                    "{} Skipping the save template in \"{}\": {:#}",
+                    "Warning:".yellow(),
+                    template_dir_path,
+                    err,
+                ),
+            }
+        }
+    }
+
+    save_templates.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(save_templates)
+}
+
+/// The environment variable takes precedence over the config file.
+fn get_templates_dir() -> Result<Option<Utf8PathBuf>> {
+    if let Ok(templates_dir) = std::env::var(SAVE_TEMPLATES_DIR_ENV_VAR) {
+        return Ok(Some(Utf8PathBuf::from(templates_dir)));
+    }
+
+    Ok(read_config()?.templates_dir)
+}
+
+fn read_save_template(template_dir_path: &Utf8Path) -> Result<SaveTemplate> {
+    let manifest_path = template_dir_path.join(SAVE_TEMPLATE_MANIFEST);
+    let manifest_json = read_to_string(&manifest_path).context(format!(
+        "Failed to read the file:\n{}",
+        manifest_path.to_string().green(),
+    ))?;
+    let manifest: Manifest = serde_json::from_str(&manifest_json).context(format!(
+        "Failed to parse the file:\n{}",
+        manifest_path.to_string().green(),
+    ))?;
+
+    let save_file_path = template_dir_path.join(SAVE_TEMPLATE_FILE_NAME);
+    if !save_file_path.exists() {
+        bail!(
+            "The template does not have a \"{}\" file.",
+            SAVE_TEMPLATE_FILE_NAME,
+        );
+    }
+
+    Ok(SaveTemplate {
+        name: manifest.name,
+        isaac_version: manifest.version,
+        description: manifest.description,
+        save_file_path,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::update_config,
+        install::{get_save_file_bytes, get_save_file_to_install},
+        test_utils::FakeTree,
+    };
+    use std::fs::{create_dir_all, write};
+
+    fn write_save_template(templates_dir: &Utf8Path, name: &str, manifest_json: &str) {
+        let template_dir = templates_dir.join(name);
+        create_dir_all(&template_dir).unwrap();
+        write(template_dir.join(SAVE_TEMPLATE_MANIFEST), manifest_json).unwrap();
+        write(
+            template_dir.join(SAVE_TEMPLATE_FILE_NAME),
+            get_save_file_bytes(IsaacVersion::Repentance).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn reads_the_templates_of_a_version() {
+        let fake_tree = FakeTree::new();
+        let templates_dir = fake_tree.root.join("templates");
+        write_save_template(
+            &templates_dir,
+            "b",
+            r#"{ "name": "B", "version": "Repentance", "description": "Everything." }"#,
+        );
+        write_save_template(
+            &templates_dir,
+            "a",
+            r#"{ "name": "A", "version": "Repentance" }"#,
+        );
+        write_save_template(
+            &templates_dir,
+            "other-version",
+            r#"{ "name": "C", "version": "RepentancePlus" }"#,
+        );
+        write_save_template(
+            &templates_dir,
+            "unknown-version",
+            r#"{ "name": "D", "version": "Repentance++" }"#,
+        );
+        update_config(|config| config.templates_dir = Some(templates_dir.clone())).unwrap();
+
+        let save_templates = get_save_templates(IsaacVersion::Repentance).unwrap();
+        let names: Vec<&str> = save_templates
+            .iter()
+            .map(|save_template| save_template.name.as_str())
+            .collect();
+        assert_eq!(names, ["A", "B"]);
+        assert_eq!(save_templates[0].description, "");
+        assert_eq!(save_templates[1].description, "Everything.");
+        assert_eq!(
+            save_templates[1].save_file_path,
+            templates_dir.join("b").join(SAVE_TEMPLATE_FILE_NAME),
+        );
+    }
+
+    #[test]
+    fn refuses_a_template_without_a_save_file() {
+        let fake_tree = FakeTree::new();
+        let template_dir = fake_tree.root.join("template");
+        create_dir_all(&template_dir).unwrap();
+        write(
+            template_dir.join(SAVE_TEMPLATE_MANIFEST),
+            r#"{ "name": "A", "version": "Repentance" }"#,
+        )
+        .unwrap();
+
+        assert!(read_save_template(&template_dir).is_err());
+    }
+
+    #[test]
+    fn refuses_a_save_file_that_does_not_match_the_manifest() {
+        let fake_tree = FakeTree::new();
+        let templates_dir = fake_tree.root.join("templates");
+        write_save_template(
+            &templates_dir,
+            "a",
+            r#"{ "name": "A", "version": "RepentancePlus" }"#,
+        );
+        update_config(|config| config.templates_dir = Some(templates_dir)).unwrap();
+
+        let save_templates = get_save_templates(IsaacVersion::RepentancePlus).unwrap();
+        assert_eq!(save_templates.len(), 1);
+        assert!(
+            get_save_file_to_install(IsaacVersion::RepentancePlus, Some(&save_templates[0]))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn prefers_the_environment_variable_to_the_config_file() {
+        let fake_tree = FakeTree::new();
+        update_config(|config| config.templates_dir = Some(fake_tree.root.join("config"))).unwrap();
+        assert_eq!(
+            get_templates_dir().unwrap(),
+            Some(fake_tree.root.join("config"))
+        );
+
+        // Only the tests that hold the fake tree lock read the environment variable.
+        std::env::set_var(SAVE_TEMPLATES_DIR_ENV_VAR, fake_tree.root.join("env"));
+        let templates_dir = get_templates_dir();
+        std::env::remove_var(SAVE_TEMPLATES_DIR_ENV_VAR);
+        assert_eq!(templates_dir.unwrap(), Some(fake_tree.root.join("env")));
+    }
+}
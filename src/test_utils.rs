@@ -0,0 +1,116 @@
+use crate::{
+    dry_run::take_dry_run_actions,
+    enums::IsaacVersion,
+    get_input::{set_input, ScriptedInput},
+    isaac_save_installer::isaac_save_installer,
+    sandbox::set_sandbox_root,
+    save_data_path::{
+        get_documents_save_data_path, get_steam_save_data_path, set_save_data_path_overrides,
+        SaveDataPathOverrides,
+    },
+    settings::{update_settings, Settings},
+};
+use anyhow::Result;
+use camino::{Utf8Path, Utf8PathBuf};
+use std::{
+    fs::{create_dir_all, write},
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+use tempfile::TempDir;
+
+pub const STEAM_USER_ID: u32 = 12345;
+
+/// Every thread reads from the same input and sees the same settings, so the tests that run the
+/// installer take turns. Each test starts with the default settings.
+static LOCK: Mutex<()> = Mutex::new(());
+
+pub fn lock() -> MutexGuard<'static, ()> {
+    let lock = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    update_settings(|settings| *settings = Settings::default());
+    take_dry_run_actions();
+
+    lock
+}
+
+/// A "Documents" directory with a directory for every version of the game and a Steam directory,
+/// with the "SteamCloud" setting turned off.
+pub struct FakeTree {
+    _temp_dir: TempDir,
+    pub root: Utf8PathBuf,
+    documents_dir: Utf8PathBuf,
+    steam_dir: Utf8PathBuf,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl FakeTree {
+    pub fn new() -> Self {
+        let lock = lock();
+        let temp_dir = TempDir::new().unwrap();
+        let root = Utf8PathBuf::from_path_buf(temp_dir.path().to_path_buf()).unwrap();
+        let fake_tree = Self {
+            _temp_dir: temp_dir,
+            documents_dir: root.join("Documents"),
+            steam_dir: root.join("Steam"),
+            root,
+            _lock: lock,
+        };
+        fake_tree.set_overrides();
+
+        create_documents_dir(&fake_tree.documents_dir);
+        create_dir_all(get_steam_save_data_path().unwrap()).unwrap();
+
+        fake_tree
+    }
+
+    /// The sandbox root keeps the config file inside of the temporary directory.
+    fn set_overrides(&self) {
+        set_sandbox_root(Some(self.root.clone()));
+        set_save_data_path_overrides(SaveDataPathOverrides {
+            documents_dir: Some(self.documents_dir.clone()),
+            steam_dir: Some(self.steam_dir.clone()),
+            steam_user_id: Some(STEAM_USER_ID),
+        });
+    }
+
+    pub fn get_save_file_path(
+        &self,
+        isaac_version: IsaacVersion,
+        save_file_slot: usize,
+    ) -> Utf8PathBuf {
+        get_documents_save_data_path(isaac_version)
+            .unwrap()
+            .join(format!("persistentgamedata{}.dat", save_file_slot))
+    }
+
+    pub fn run(&self, answers: &[&str]) -> Result<()> {
+        self.set_overrides();
+        set_input(Box::new(ScriptedInput::new(
+            answers.iter().map(|answer| answer.to_string()).collect(),
+        )));
+
+        isaac_save_installer()
+    }
+}
+
+pub fn create_documents_dir(documents_dir: &Utf8Path) {
+    for isaac_version in (0..).map_while(IsaacVersion::from_repr) {
+        let version_dir = documents_dir
+            .join("My Games")
+            .join(get_documents_dir_name(isaac_version));
+        create_dir_all(&version_dir).unwrap();
+        write(version_dir.join("log.txt"), "").unwrap();
+        write(version_dir.join("options.ini"), "[Options]\nSteamCloud=0\n").unwrap();
+    }
+}
+
+pub fn get_documents_dir_name(isaac_version: IsaacVersion) -> &'static str {
+    match isaac_version {
+        IsaacVersion::Rebirth => "Binding of Isaac Rebirth",
+        IsaacVersion::Afterbirth => "Binding of Isaac Afterbirth",
+        IsaacVersion::AfterbirthPlus | IsaacVersion::AfterbirthPlusBP5 => {
+            "Binding of Isaac Afterbirth+"
+        }
+        IsaacVersion::Repentance => "Binding of Isaac Repentance",
+        IsaacVersion::RepentancePlus => "Binding of Isaac Repentance+",
+    }
+}
@@ -0,0 +1,1219 @@
+use crate::{
+    backup::backup_save_file,
+    challenges::get_challenge_ids,
+    collection::get_collectible_ids,
+    config::{read_config, update_config},
+    delete::delete_save_file,
+    dry_run::{
+        get_success_prefix, is_dry_run, print_dry_run_actions, set_dry_run, take_dry_run_actions,
+    },
+    enums::{Activity, IsaacVersion},
+    errors::{ErrorKind, InstallerError},
+    get_input::prompt_for_user_to_hit_enter,
+    history::get_history_entry_lines,
+    install::{get_save_file_to_install, install_save_file_bytes},
+    isaac_save_installer::{
+        check_if_isaac_open, get_existing_save_files, run_activity, run_activity_for_slot,
+    },
+    persistent_game_data::parse_persistent_game_data,
+    print_error,
+    reset::{get_new_persistent_game_data, reset_save_file},
+    save_data_path::{
+        get_documents_save_data_path, get_steam_cloud_enabled, get_steam_save_data_path,
+        toggle_steam_cloud_enabled,
+    },
+    set_up_paths::set_up_paths,
+    templates::{get_save_templates, SaveTemplate},
+    undo::{get_operation_to_undo, undo_operation},
+};
+use anyhow::{bail, Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use crossterm::{
+    cursor::Show,
+    event::{read, Event, KeyCode, KeyEventKind},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap},
+    Terminal,
+};
+use std::{
+    io::{stdout, Stdout},
+    panic::{set_hook, take_hook},
+};
+
+type TuiTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+const NUM_SAVE_FILES: usize = 3;
+
+/// The activities that need more input than the TUI can show are run with the normal prompts.
+const OTHER_ACTIVITIES: [(Activity, &str); 9] = [
+    (Activity::Import, "Install a save file from your computer"),
+    (Activity::Statistics, "View or edit the statistics"),
+    (Activity::Collection, "View or edit the collection page"),
+    (Activity::Challenges, "View or edit the challenges"),
+    (Activity::Bestiary, "View the bestiary (Repentance only)"),
+    (Activity::Progress, "See what is left to unlock"),
+    (Activity::Diff, "Compare two save files"),
+    (Activity::Convert, "Convert to another version of the game"),
+    (
+        Activity::ManageSlots,
+        "Copy, move, or swap save files between slots",
+    ),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Panel {
+    Versions,
+    Slots,
+}
+
+#[derive(Clone, Copy)]
+enum LogLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+enum Dialog {
+    Pirate,
+    Confirm {
+        title: String,
+        lines: Vec<String>,
+        action: PendingAction,
+    },
+    Choose {
+        title: String,
+        options: Vec<String>,
+        state: ListState,
+        choice: Choice,
+    },
+}
+
+enum Choice {
+    SaveTemplate,
+    OtherActivity,
+}
+
+/// The keys are handled without the terminal, so that they can be tested. Anything that needs the
+/// terminal is returned to the main loop instead.
+enum TerminalRequest {
+    /// Some of the functions that are shared with the normal prompts print to the terminal.
+    Clear,
+    SetUpPaths,
+    OtherActivity(Activity),
+}
+
+enum PendingAction {
+    /// `None` is the built-in fully-unlocked save file. Otherwise, this is an index into the save
+    /// templates.
+    Install(Option<usize>),
+    Delete,
+    Reset,
+    ToggleSteamCloud,
+    Undo,
+}
+
+/// The state of the version that is currently loaded.
+struct Loaded {
+    isaac_version: IsaacVersion,
+    documents_save_data_path: Utf8PathBuf,
+    steam_cloud_enabled: bool,
+    existing_save_files: Vec<(Utf8PathBuf, bool)>,
+    slot_summaries: Vec<Vec<String>>,
+    save_templates: Vec<SaveTemplate>,
+}
+
+struct App {
+    panel: Panel,
+    versions_state: ListState,
+    slots_state: ListState,
+    loaded: Option<Loaded>,
+    log: Vec<(LogLevel, String)>,
+    dialog: Option<Dialog>,
+    quit: bool,
+}
+
+pub fn tui() -> Result<()> {
+    check_if_isaac_open()?;
+
+    let mut terminal = enter_tui()?;
+    let result = run(&mut terminal);
+    leave_tui(&mut terminal)?;
+
+    result
+}
+
+fn enter_tui() -> Result<TuiTerminal> {
+    // The error messages are shown in the log pane, which cannot display color codes.
+    colored::control::set_override(false);
+
+    // A panic would otherwise leave the terminal in raw mode on the alternate screen, which breaks
+    // the terminal of the user (even over SSH).
+    let default_hook = take_hook();
+    set_hook(Box::new(move |panic_info| {
+        restore_terminal().ok();
+        default_hook(panic_info);
+    }));
+
+    enable_raw_mode().context("Failed to enable the raw mode of the terminal.")?;
+    execute!(stdout(), EnterAlternateScreen).context("Failed to enter the alternate screen.")?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))
+        .context("Failed to initialize the terminal.")?;
+    terminal.clear().context("Failed to clear the terminal.")?;
+
+    Ok(terminal)
+}
+
+fn leave_tui(terminal: &mut TuiTerminal) -> Result<()> {
+    // The panic hook from `enter_tui` is replaced with the default one.
+    drop(take_hook());
+
+    restore_terminal()?;
+    terminal
+        .show_cursor()
+        .context("Failed to show the cursor.")?;
+
+    Ok(())
+}
+
+/// This does not need the terminal, so that it can also be called from the panic hook.
+fn restore_terminal() -> Result<()> {
+    disable_raw_mode().context("Failed to disable the raw mode of the terminal.")?;
+    execute!(stdout(), LeaveAlternateScreen, Show)
+        .context("Failed to leave the alternate screen.")?;
+    colored::control::unset_override();
+
+    Ok(())
+}
+
+fn run(terminal: &mut TuiTerminal) -> Result<()> {
+    let config = read_config()?;
+    let isaac_version = config
+        .last_isaac_version
+        .unwrap_or(IsaacVersion::Repentance);
+    let save_file_slot = config.last_save_file_slot.unwrap_or(1);
+
+    let mut app = App::new(isaac_version, save_file_slot);
+
+    while !app.quit {
+        terminal
+            .draw(|frame| draw(frame, &mut app))
+            .context("Failed to draw the terminal.")?;
+
+        let Event::Key(key) = read().context("Failed to read from the terminal.")? else {
+            continue;
+        };
+
+        // On Windows, there is an event for releasing the key as well.
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match handle_key_press(&mut app, key.code)? {
+            Some(TerminalRequest::Clear) => {
+                terminal.clear().context("Failed to clear the terminal.")?;
+            }
+            Some(TerminalRequest::SetUpPaths) => run_set_up_paths(terminal, &mut app)?,
+            Some(TerminalRequest::OtherActivity(activity)) => {
+                run_other_activity(terminal, &mut app, activity)?;
+            }
+            None => {}
+        }
+    }
+
+    if app.loaded.is_some() {
+        let save_file_slot = get_selected_slot(&app);
+        update_config(|config| config.last_save_file_slot = Some(save_file_slot))?;
+    }
+
+    Ok(())
+}
+
+fn handle_key_press(app: &mut App, key_code: KeyCode) -> Result<Option<TerminalRequest>> {
+    match app.dialog.take() {
+        Some(dialog) => handle_dialog_key(app, dialog, key_code),
+        None => handle_key(app, key_code),
+    }
+}
+
+fn handle_key(app: &mut App, key_code: KeyCode) -> Result<Option<TerminalRequest>> {
+    match key_code {
+        KeyCode::Char('q') | KeyCode::Esc => app.quit = true,
+        KeyCode::Tab | KeyCode::BackTab | KeyCode::Left | KeyCode::Right => {
+            app.panel = match app.panel {
+                Panel::Versions => Panel::Slots,
+                Panel::Slots => Panel::Versions,
+            };
+        }
+        KeyCode::Up | KeyCode::Down => {
+            let (state, len) = match app.panel {
+                Panel::Versions => (&mut app.versions_state, get_isaac_versions().len()),
+                Panel::Slots => (&mut app.slots_state, NUM_SAVE_FILES),
+            };
+            let selected = state.selected().unwrap_or_default();
+            let selected = match key_code {
+                KeyCode::Up => selected.checked_sub(1).unwrap_or(len - 1),
+                _ => (selected + 1) % len,
+            };
+            state.select(Some(selected));
+        }
+        KeyCode::Enter if app.panel == Panel::Versions => {
+            let index = app.versions_state.selected().unwrap_or_default();
+            let isaac_version = IsaacVersion::from_repr(index).context("Invalid game.")?;
+            load(app, isaac_version);
+            if app.loaded.is_some() {
+                app.panel = Panel::Slots;
+            }
+            return Ok(Some(TerminalRequest::Clear));
+        }
+        // The paths can be set up before a game is loaded, since loading fails without them.
+        KeyCode::Char('p') => return Ok(Some(TerminalRequest::SetUpPaths)),
+        KeyCode::Char('x') => {
+            set_dry_run(!is_dry_run());
+            match is_dry_run() {
+                true => app.log(
+                    LogLevel::Warning,
+                    "Dry run: nothing will be changed. You will be shown what would have been changed instead.",
+                ),
+                false => app.log(LogLevel::Info, "Dry-run mode is off. Files will be changed again."),
+            }
+        }
+        KeyCode::Char('u') => match get_operation_to_undo() {
+            Ok(history_entry) => {
+                let mut lines = vec!["This will undo the following change:".to_string()];
+                lines.extend(get_history_entry_lines(&history_entry));
+                app.dialog = Some(Dialog::Confirm {
+                    title: "Undo".to_string(),
+                    lines,
+                    action: PendingAction::Undo,
+                });
+            }
+            Err(err) => app.log(LogLevel::Warning, &err.to_string()),
+        },
+        KeyCode::Char(character) => {
+            let Some(loaded) = &app.loaded else {
+                app.log(LogLevel::Warning, "Select a game first.");
+                return Ok(None);
+            };
+            let (save_file_path, exists) = match get_selected_save_file(app, loaded) {
+                Ok(save_file) => save_file.clone(),
+                Err(err) => {
+                    app.log(LogLevel::Error, &err.to_string());
+                    return Ok(None);
+                }
+            };
+            let slot = get_selected_slot(app);
+
+            app.dialog = match character {
+                'i' => match loaded.save_templates.is_empty() {
+                    true => match confirm_install(&save_file_path, exists, None) {
+                        Some(dialog) => Some(dialog),
+                        None => return run_pending_action(app, PendingAction::Install(None)),
+                    },
+                    false => Some(Dialog::Choose {
+                        title: "Which save file do you want to install?".to_string(),
+                        options: std::iter::once("The built-in fully-unlocked save file".to_string())
+                            .chain(
+                                loaded
+                                    .save_templates
+                                    .iter()
+                                    .map(|save_template| save_template.name.clone()),
+                            )
+                            .collect(),
+                        state: ListState::default().with_selected(Some(0)),
+                        choice: Choice::SaveTemplate,
+                    }),
+                },
+                'b' => {
+                    run_operation(app, |_| match exists {
+                        true => backup_save_file(&save_file_path).map(|destination_path| {
+                            format!(
+                                "{} slot {} to: {}",
+                                get_success_prefix("backed up", "back up"),
+                                slot,
+                                destination_path,
+                            )
+                        }),
+                        false => bail!(InstallerError::new(
+                            ErrorKind::SlotEmpty,
+                            format!("Slot {} is empty, so there is nothing to backup.", slot),
+                        )),
+                    });
+                    None
+                }
+                'd' => match exists {
+                    true => Some(Dialog::Confirm {
+                        title: "Delete".to_string(),
+                        lines: vec![
+                            "This will permanently delete the following save file:".to_string(),
+                            save_file_path.to_string(),
+                        ],
+                        action: PendingAction::Delete,
+                    }),
+                    false => {
+                        app.log(
+                            LogLevel::Warning,
+                            &format!("Slot {} is empty, so there is nothing to delete.", slot),
+                        );
+                        None
+                    }
+                },
+                'r' => match exists {
+                    true => Some(Dialog::Confirm {
+                        title: "Reset".to_string(),
+                        lines: vec![
+                            "This will permanently erase all of the progress in the following save file:".to_string(),
+                            save_file_path.to_string(),
+                        ],
+                        action: PendingAction::Reset,
+                    }),
+                    false => return run_pending_action(app, PendingAction::Reset),
+                },
+                'c' => {
+                    let verb = match loaded.steam_cloud_enabled {
+                        true => "off",
+                        false => "on",
+                    };
+                    Some(Dialog::Confirm {
+                        title: "SteamCloud".to_string(),
+                        lines: vec![format!(
+                            "Do you want to turn the \"SteamCloud\" setting {}? The game will then read the save files in the \"{}\" directory.",
+                            verb,
+                            match loaded.steam_cloud_enabled {
+                                true => "Documents",
+                                false => "Steam",
+                            },
+                        )],
+                        action: PendingAction::ToggleSteamCloud,
+                    })
+                }
+                'o' => Some(Dialog::Choose {
+                    title: "What do you want to do?".to_string(),
+                    options: OTHER_ACTIVITIES
+                        .iter()
+                        .map(|(_, description)| description.to_string())
+                        .collect(),
+                    state: ListState::default().with_selected(Some(0)),
+                    choice: Choice::OtherActivity,
+                }),
+                _ => None,
+            };
+        }
+        _ => {}
+    }
+
+    Ok(None)
+}
+
+fn handle_dialog_key(
+    app: &mut App,
+    dialog: Dialog,
+    key_code: KeyCode,
+) -> Result<Option<TerminalRequest>> {
+    match dialog {
+        Dialog::Pirate => match key_code {
+            KeyCode::Char('y') => {}
+            KeyCode::Char('n') => {
+                app.quit = true;
+                bail!("This installer will only work with the official Steam version of the game.");
+            }
+            _ => app.dialog = Some(Dialog::Pirate),
+        },
+        Dialog::Confirm {
+            title,
+            lines,
+            action,
+        } => match key_code {
+            KeyCode::Char('y') => return run_pending_action(app, action),
+            KeyCode::Char('n') | KeyCode::Esc => app.log(LogLevel::Info, "Canceled."),
+            _ => {
+                app.dialog = Some(Dialog::Confirm {
+                    title,
+                    lines,
+                    action,
+                });
+            }
+        },
+        Dialog::Choose {
+            title,
+            options,
+            mut state,
+            choice,
+        } => {
+            let selected = state.selected().unwrap_or_default();
+            match key_code {
+                KeyCode::Enter => match choice {
+                    Choice::SaveTemplate => {
+                        let save_template_index = selected.checked_sub(1);
+                        if let Some(loaded) = &app.loaded {
+                            if let Ok((save_file_path, exists)) =
+                                get_selected_save_file(app, loaded)
+                            {
+                                app.dialog =
+                                    confirm_install(save_file_path, *exists, save_template_index);
+                            }
+                        }
+                        if app.dialog.is_none() {
+                            return run_pending_action(
+                                app,
+                                PendingAction::Install(save_template_index),
+                            );
+                        }
+                    }
+                    Choice::OtherActivity => {
+                        let (activity, _) = OTHER_ACTIVITIES[selected];
+                        return Ok(Some(TerminalRequest::OtherActivity(activity)));
+                    }
+                },
+                KeyCode::Esc => app.log(LogLevel::Info, "Canceled."),
+                KeyCode::Up | KeyCode::Down => {
+                    let selected = match key_code {
+                        KeyCode::Up => selected.checked_sub(1).unwrap_or(options.len() - 1),
+                        _ => (selected + 1) % options.len(),
+                    };
+                    state.select(Some(selected));
+                    app.dialog = Some(Dialog::Choose {
+                        title,
+                        options,
+                        state,
+                        choice,
+                    });
+                }
+                _ => {
+                    app.dialog = Some(Dialog::Choose {
+                        title,
+                        options,
+                        state,
+                        choice,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Returns `None` if there is nothing to confirm.
+fn confirm_install(
+    save_file_path: &Utf8Path,
+    exists: bool,
+    save_template_index: Option<usize>,
+) -> Option<Dialog> {
+    match exists {
+        true => Some(Dialog::Confirm {
+            title: "Install".to_string(),
+            lines: vec![
+                "The following save file already exists and will be overwritten:".to_string(),
+                save_file_path.to_string(),
+            ],
+            action: PendingAction::Install(save_template_index),
+        }),
+        false => None,
+    }
+}
+
+fn run_pending_action(app: &mut App, action: PendingAction) -> Result<Option<TerminalRequest>> {
+    // Undoing does not need a game to be loaded, since it can change the files of any game.
+    if let PendingAction::Undo = action {
+        run_undo(app);
+        return Ok(Some(TerminalRequest::Clear));
+    }
+
+    let Some(loaded) = &app.loaded else {
+        return Ok(None);
+    };
+    let isaac_version = loaded.isaac_version;
+    let slot = get_selected_slot(app);
+
+    match action {
+        PendingAction::Install(save_template_index) => run_operation(app, |loaded| {
+            let save_template =
+                save_template_index.and_then(|index| loaded.save_templates.get(index));
+            let (save_file_bytes, description) =
+                get_save_file_to_install(isaac_version, save_template)?;
+            let (save_file_path, _) = get_save_file(loaded, slot)?;
+            install_save_file_bytes(save_file_path, &save_file_bytes)?;
+
+            Ok(format!(
+                "{} {} to slot {}.",
+                get_success_prefix("installed", "install"),
+                description,
+                slot,
+            ))
+        }),
+        PendingAction::Delete => run_operation(app, |loaded| {
+            let (save_file_path, _) = get_save_file(loaded, slot)?;
+            delete_save_file(save_file_path)?;
+
+            Ok(format!(
+                "{} slot {}.",
+                get_success_prefix("deleted", "delete"),
+                slot,
+            ))
+        }),
+        PendingAction::Reset => run_operation(app, |loaded| {
+            let persistent_game_data = get_new_persistent_game_data(isaac_version)?;
+            let (save_file_path, _) = get_save_file(loaded, slot)?;
+            reset_save_file(save_file_path, &persistent_game_data)?;
+
+            Ok(format!(
+                "{} a brand-new save file to slot {}.",
+                get_success_prefix("installed", "install"),
+                slot,
+            ))
+        }),
+        PendingAction::ToggleSteamCloud => {
+            run_operation(app, |loaded| {
+                toggle_steam_cloud_enabled(
+                    &loaded.documents_save_data_path,
+                    loaded.steam_cloud_enabled,
+                )?;
+
+                Ok(format!(
+                    "{} the \"SteamCloud\" setting.",
+                    get_success_prefix("changed", "change"),
+                ))
+            });
+
+            // The save files are now read from a different directory.
+            load(app, isaac_version);
+        }
+        PendingAction::Undo => unreachable!(),
+    }
+
+    Ok(Some(TerminalRequest::Clear))
+}
+
+fn run_undo(app: &mut App) {
+    let result = check_if_isaac_open().and_then(|()| {
+        let history_entry = get_operation_to_undo()?;
+        undo_operation(&history_entry)?;
+        Ok(format!(
+            "{} the {} of: {}",
+            get_success_prefix("undid", "undo"),
+            history_entry.operation,
+            history_entry.destination_path,
+        ))
+    });
+    match result {
+        Ok(message) => app.log(LogLevel::Success, &message),
+        Err(err) => app.log(LogLevel::Error, &err.to_string()),
+    }
+    app.log_dry_run_actions();
+
+    // The "SteamCloud" setting might have changed as well as the save files.
+    if let Some(isaac_version) = app.loaded.as_ref().map(|loaded| loaded.isaac_version) {
+        load(app, isaac_version);
+    }
+}
+
+/// Runs something that changes the files for the loaded version, logs the result, and then reads
+/// the save files again.
+fn run_operation(app: &mut App, operation: impl FnOnce(&Loaded) -> Result<String>) {
+    let Some(loaded) = &app.loaded else {
+        return;
+    };
+
+    let result = check_if_isaac_open().and_then(|()| operation(loaded));
+    match result {
+        Ok(message) => app.log(LogLevel::Success, &message),
+        Err(err) => app.log(LogLevel::Error, &err.to_string()),
+    }
+    app.log_dry_run_actions();
+    app.refresh_save_files();
+}
+
+fn run_other_activity(terminal: &mut TuiTerminal, app: &mut App, activity: Activity) -> Result<()> {
+    let Some(loaded) = &app.loaded else {
+        return Ok(());
+    };
+    let isaac_version = loaded.isaac_version;
+    let slot = get_selected_slot(app);
+
+    leave_tui(terminal)?;
+    let result = match activity {
+        Activity::Diff | Activity::ManageSlots => run_activity(
+            activity,
+            isaac_version,
+            &loaded.documents_save_data_path,
+            loaded.steam_cloud_enabled,
+            &loaded.existing_save_files,
+        ),
+        _ => {
+            println!("Slot {}:", slot);
+            println!();
+            check_if_isaac_open().and_then(|()| {
+                run_activity_for_slot(activity, slot, isaac_version, &loaded.existing_save_files)
+            })
+        }
+    };
+    match &result {
+        Ok(()) => println!(),
+        Err(err) => print_error(err),
+    }
+    if is_dry_run() {
+        print_dry_run_actions();
+        println!();
+    }
+    println!("Press enter to go back.");
+    prompt_for_user_to_hit_enter().ok();
+    *terminal = enter_tui()?;
+
+    let description = OTHER_ACTIVITIES
+        .iter()
+        .find(|(other_activity, _)| *other_activity == activity)
+        .map(|(_, description)| *description)
+        .unwrap_or_default();
+    match result {
+        Ok(()) => app.log(LogLevel::Success, &format!("Finished: {}", description)),
+        Err(err) => app.log(LogLevel::Error, &err.to_string()),
+    }
+    app.refresh_save_files();
+
+    Ok(())
+}
+
+fn run_set_up_paths(terminal: &mut TuiTerminal, app: &mut App) -> Result<()> {
+    leave_tui(terminal)?;
+    let result = set_up_paths();
+    match &result {
+        Ok(()) => println!(),
+        Err(err) => print_error(err),
+    }
+    println!("Press enter to go back.");
+    prompt_for_user_to_hit_enter().ok();
+    *terminal = enter_tui()?;
+
+    match result {
+        Ok(()) => app.log(LogLevel::Success, "Saved the paths to your save files."),
+        Err(err) => app.log(LogLevel::Error, &err.to_string()),
+    }
+
+    // The save files might be somewhere else now.
+    if let Some(isaac_version) = app.loaded.as_ref().map(|loaded| loaded.isaac_version) {
+        load(app, isaac_version);
+    }
+
+    // Invalid save templates print a warning.
+    terminal.clear().context("Failed to clear the terminal.")?;
+
+    Ok(())
+}
+
+/// If the save files cannot be found, the error is shown in the log and no game is loaded.
+fn load(app: &mut App, isaac_version: IsaacVersion) {
+    match get_loaded(isaac_version) {
+        Ok(loaded) => {
+            app.log(
+                LogLevel::Info,
+                &format!("Loaded the save files for {}.", isaac_version),
+            );
+            if let Err(err) =
+                update_config(|config| config.last_isaac_version = Some(isaac_version))
+            {
+                app.log(LogLevel::Warning, &err.to_string());
+            }
+            if loaded.steam_cloud_enabled {
+                app.log(
+                    LogLevel::Warning,
+                    "You have \"SteamCloud=1\" in your options.ini file, which is not recommended, since it can interfere with installing a full save file. Press c to turn it off.",
+                );
+            }
+            app.loaded = Some(loaded);
+        }
+        Err(err) => {
+            app.log(LogLevel::Error, &err.to_string());
+            app.log(
+                LogLevel::Info,
+                "If your save files are somewhere else, press p to type the paths to them.",
+            );
+            app.loaded = None;
+        }
+    }
+}
+
+fn get_loaded(isaac_version: IsaacVersion) -> Result<Loaded> {
+    let documents_save_data_path = get_documents_save_data_path(isaac_version)?;
+    let steam_cloud_enabled = get_steam_cloud_enabled(&documents_save_data_path)?;
+    let save_templates = get_save_templates(isaac_version)?;
+
+    let mut loaded = Loaded {
+        isaac_version,
+        documents_save_data_path,
+        steam_cloud_enabled,
+        existing_save_files: Vec::new(),
+        slot_summaries: Vec::new(),
+        save_templates,
+    };
+    refresh_save_files(&mut loaded)?;
+
+    Ok(loaded)
+}
+
+fn refresh_save_files(loaded: &mut Loaded) -> Result<()> {
+    // The Steam directory is only needed for Steam Cloud, so it is only looked for then.
+    let save_data_path = match loaded.steam_cloud_enabled {
+        true => get_steam_save_data_path()?,
+        false => loaded.documents_save_data_path.clone(),
+    };
+    loaded.existing_save_files = get_existing_save_files(
+        loaded.isaac_version,
+        &save_data_path,
+        loaded.steam_cloud_enabled,
+    );
+    loaded.slot_summaries = loaded
+        .existing_save_files
+        .iter()
+        .map(|save_file| get_slot_summary(save_file, loaded.isaac_version))
+        .collect();
+
+    Ok(())
+}
+
+fn get_slot_summary(
+    (save_file_path, exists): &(Utf8PathBuf, bool),
+    isaac_version: IsaacVersion,
+) -> Vec<String> {
+    if !exists {
+        return vec!["[empty]".to_string()];
+    }
+
+    let mut summary = vec![save_file_path.to_string()];
+    match parse_persistent_game_data(save_file_path) {
+        Ok(persistent_game_data) => match persistent_game_data.get_isaac_version() {
+            Some(file_isaac_version) if file_isaac_version == isaac_version => {
+                let achievement_ids: Vec<usize> =
+                    (1..persistent_game_data.achievements.len()).collect();
+                let challenge_ids = get_challenge_ids(persistent_game_data.challenges.len());
+                let collectible_ids =
+                    get_collectible_ids(persistent_game_data.collectibles.len(), isaac_version);
+                summary.push(format!(
+                    "Achievements: {}   Challenges: {}   Collection: {}",
+                    count_unlocked(&persistent_game_data.achievements, &achievement_ids),
+                    count_unlocked(&persistent_game_data.challenges, &challenge_ids),
+                    count_unlocked(&persistent_game_data.collectibles, &collectible_ids),
+                ));
+            }
+            Some(file_isaac_version) => {
+                summary.push(format!("This save file is for {}.", file_isaac_version));
+            }
+            None => summary.push("The version of this save file is unknown.".to_string()),
+        },
+        Err(err) => summary.push(format!("Failed to read the save file: {}", err)),
+    }
+
+    summary
+}
+
+fn count_unlocked(flags: &[u8], ids: &[usize]) -> String {
+    let num_unlocked = ids.iter().filter(|id| flags[**id] != 0).count();
+    format!("{}/{}", num_unlocked, ids.len())
+}
+
+fn get_isaac_versions() -> Vec<IsaacVersion> {
+    (0..).map_while(IsaacVersion::from_repr).collect()
+}
+
+fn get_selected_slot(app: &App) -> usize {
+    app.slots_state.selected().unwrap_or_default() + 1
+}
+
+fn get_selected_save_file<'a>(app: &App, loaded: &'a Loaded) -> Result<&'a (Utf8PathBuf, bool)> {
+    get_save_file(loaded, get_selected_slot(app))
+}
+
+fn get_save_file(loaded: &Loaded, slot: usize) -> Result<&(Utf8PathBuf, bool)> {
+    loaded
+        .existing_save_files
+        .get(slot - 1)
+        .context(format!("Failed to find the save file for slot {}.", slot))
+}
+
+impl App {
+    fn new(isaac_version: IsaacVersion, save_file_slot: usize) -> Self {
+        let mut app = Self {
+            panel: Panel::Versions,
+            versions_state: ListState::default().with_selected(Some(isaac_version as usize)),
+            slots_state: ListState::default().with_selected(Some(save_file_slot - 1)),
+            loaded: None,
+            log: Vec::new(),
+            dialog: Some(Dialog::Pirate),
+            quit: false,
+        };
+        app.log(
+            LogLevel::Info,
+            "Select a game and press enter to see its save files.",
+        );
+
+        app
+    }
+
+    /// If the save files cannot be read any more, the error is shown and the game is unloaded.
+    fn refresh_save_files(&mut self) {
+        let Some(loaded) = &mut self.loaded else {
+            return;
+        };
+        if let Err(err) = refresh_save_files(loaded) {
+            self.log(LogLevel::Error, &err.to_string());
+            self.loaded = None;
+        }
+    }
+
+    fn log(&mut self, log_level: LogLevel, message: &str) {
+        for line in message.lines() {
+            self.log.push((log_level, line.to_string()));
+        }
+    }
+
+    /// In a dry run, the changes that would have been made are shown instead.
+    fn log_dry_run_actions(&mut self) {
+        if !is_dry_run() {
+            return;
+        }
+
+        let actions = take_dry_run_actions();
+        self.log(
+            LogLevel::Warning,
+            "Dry run: nothing was changed. These are the changes that would have been made:",
+        );
+        if actions.is_empty() {
+            self.log(LogLevel::Info, "No files would have been changed.");
+        }
+        for action in actions {
+            self.log(LogLevel::Info, &format!("- {}", action));
+        }
+    }
+}
+
+fn draw(frame: &mut ratatui::Frame, app: &mut App) {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(8),
+            Constraint::Length(10),
+            Constraint::Length(1),
+        ])
+        .split(frame.size());
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(38), Constraint::Min(20)])
+        .split(rows[0]);
+
+    draw_versions(frame, app, columns[0]);
+    draw_slots(frame, app, columns[1]);
+    draw_log(frame, app, rows[1]);
+    draw_action_bar(frame, app, rows[2]);
+
+    match &mut app.dialog {
+        Some(Dialog::Pirate) => draw_confirm(
+            frame,
+            "Welcome",
+            &["Did you legally purchase the game on Steam?".to_string()],
+        ),
+        Some(Dialog::Confirm { title, lines, .. }) => draw_confirm(frame, title, lines),
+        Some(Dialog::Choose {
+            title,
+            options,
+            state,
+            ..
+        }) => {
+            let area = get_centered_rect(frame.size(), options.len() as u16 + 2);
+            let items: Vec<ListItem> = options
+                .iter()
+                .map(|option| ListItem::new(option.as_str()))
+                .collect();
+            let list = List::new(items)
+                .block(get_block(title, true))
+                .highlight_style(get_highlight_style())
+                .highlight_symbol("> ");
+            frame.render_widget(Clear, area);
+            frame.render_stateful_widget(list, area, state);
+        }
+        None => {}
+    }
+}
+
+fn draw_versions(frame: &mut ratatui::Frame, app: &mut App, area: Rect) {
+    let loaded_isaac_version = app.loaded.as_ref().map(|loaded| loaded.isaac_version);
+    let items: Vec<ListItem> = get_isaac_versions()
+        .into_iter()
+        .map(|isaac_version| {
+            let marker = match Some(isaac_version) == loaded_isaac_version {
+                true => "* ",
+                false => "  ",
+            };
+            ListItem::new(format!("{}{}", marker, isaac_version))
+        })
+        .collect();
+    let list = List::new(items)
+        .block(get_block("Game", app.panel == Panel::Versions))
+        .highlight_style(get_highlight_style())
+        .highlight_symbol("> ");
+
+    frame.render_stateful_widget(list, area, &mut app.versions_state);
+}
+
+fn draw_slots(frame: &mut ratatui::Frame, app: &mut App, area: Rect) {
+    let Some(loaded) = &app.loaded else {
+        let paragraph = Paragraph::new("Select a game and press enter.")
+            .block(get_block("Save slots", app.panel == Panel::Slots));
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let items: Vec<ListItem> = loaded
+        .slot_summaries
+        .iter()
+        .enumerate()
+        .map(|(i, summary)| {
+            let mut lines = vec![Line::from(Span::styled(
+                format!("Slot {}", i + 1),
+                Style::default().add_modifier(Modifier::BOLD),
+            ))];
+            lines.extend(summary.iter().map(|line| Line::from(format!("  {}", line))));
+            lines.push(Line::from(""));
+            ListItem::new(lines)
+        })
+        .collect();
+    let steam_cloud = match loaded.steam_cloud_enabled {
+        true => "on",
+        false => "off",
+    };
+    let title = format!(
+        "Save slots for {} (SteamCloud {})",
+        loaded.isaac_version, steam_cloud,
+    );
+    let list = List::new(items)
+        .block(get_block(&title, app.panel == Panel::Slots))
+        .highlight_style(get_highlight_style())
+        .highlight_symbol("> ");
+
+    frame.render_stateful_widget(list, area, &mut app.slots_state);
+}
+
+fn draw_log(frame: &mut ratatui::Frame, app: &App, area: Rect) {
+    let width = area.width.saturating_sub(2).max(1) as usize;
+    let height = area.height.saturating_sub(2) as usize;
+
+    // Long lines are wrapped by hand so that the newest lines are always the ones that are shown.
+    let mut lines: Vec<Line> = Vec::new();
+    for (log_level, message) in &app.log {
+        let color = match log_level {
+            LogLevel::Info => Color::Reset,
+            LogLevel::Success => Color::Green,
+            LogLevel::Warning => Color::Yellow,
+            LogLevel::Error => Color::Red,
+        };
+        let characters: Vec<char> = message.chars().collect();
+        for chunk in characters.chunks(width) {
+            lines.push(Line::from(Span::styled(
+                chunk.iter().collect::<String>(),
+                Style::default().fg(color),
+            )));
+        }
+    }
+    let lines = lines.split_off(lines.len().saturating_sub(height));
+    let title = match is_dry_run() {
+        true => "Log (dry run)",
+        false => "Log",
+    };
+    let paragraph = Paragraph::new(lines).block(get_block(title, false));
+
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_action_bar(frame: &mut ratatui::Frame, app: &App, area: Rect) {
+    let actions: &[(&str, &str)] = match (&app.dialog, app.panel) {
+        (Some(Dialog::Pirate | Dialog::Confirm { .. }), _) => &[("y", "Yes"), ("n", "No")],
+        (Some(Dialog::Choose { .. }), _) => &[
+            ("Up/Down", "Select"),
+            ("Enter", "Choose"),
+            ("Esc", "Cancel"),
+        ],
+        (None, Panel::Versions) => &[
+            ("Up/Down", "Select"),
+            ("Enter", "Load"),
+            ("Tab", "Save slots"),
+            ("u", "Undo"),
+            ("p", "Paths"),
+            ("x", "Dry run"),
+            ("q", "Quit"),
+        ],
+        (None, Panel::Slots) => &[
+            ("i", "Install"),
+            ("b", "Backup"),
+            ("d", "Delete"),
+            ("r", "Reset"),
+            ("c", "SteamCloud"),
+            ("o", "Other"),
+            ("u", "Undo"),
+            ("p", "Paths"),
+            ("x", "Dry run"),
+            ("Tab", "Game"),
+            ("q", "Quit"),
+        ],
+    };
+
+    let spans: Vec<Span> = actions
+        .iter()
+        .flat_map(|(key, description)| {
+            [
+                Span::styled(
+                    format!(" {} ", key),
+                    Style::default().add_modifier(Modifier::REVERSED),
+                ),
+                Span::raw(format!(" {}  ", description)),
+            ]
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn draw_confirm(frame: &mut ratatui::Frame, title: &str, lines: &[String]) {
+    let mut text: Vec<Line> = lines.iter().map(|line| Line::from(line.as_str())).collect();
+    text.push(Line::from(""));
+    text.push(Line::from("Press y for yes or n for no."));
+
+    let area = get_centered_rect(frame.size(), text.len() as u16 + 4);
+    let paragraph = Paragraph::new(text)
+        .block(get_block(title, true))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph, area);
+}
+
+fn get_block(title: &str, focused: bool) -> Block<'_> {
+    let border_style = match focused {
+        true => Style::default().fg(Color::Cyan),
+        false => Style::default(),
+    };
+
+    Block::default()
+        .title(format!(" {} ", title))
+        .borders(Borders::ALL)
+        .border_style(border_style)
+}
+
+fn get_highlight_style() -> Style {
+    Style::default().add_modifier(Modifier::REVERSED)
+}
+
+/// Returns an area in the middle of the screen that is 80% as wide as the screen.
+fn get_centered_rect(area: Rect, height: u16) -> Rect {
+    let width = area.width * 4 / 5;
+    let height = height.min(area.height);
+
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        constants::OPTIONS_INI,
+        save_data_path::{
+            get_save_data_path_overrides, set_save_data_path_overrides, SaveDataPathOverrides,
+        },
+        test_utils::FakeTree,
+    };
+    use std::fs::write;
+
+    /// Accepts the welcome dialog and loads Repentance with slot 1 selected.
+    fn load_repentance() -> App {
+        let mut app = App::new(IsaacVersion::Repentance, 1);
+        press(&mut app, &[KeyCode::Char('y'), KeyCode::Enter]);
+        app
+    }
+
+    fn press(app: &mut App, key_codes: &[KeyCode]) {
+        for key_code in key_codes {
+            handle_key_press(app, *key_code).unwrap();
+        }
+    }
+
+    fn get_last_log_line(app: &App) -> &str {
+        app.log.last().map(|(_, line)| line.as_str()).unwrap()
+    }
+
+    #[test]
+    fn installs_and_deletes_a_save_file() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        let mut app = load_repentance();
+        assert!(app.loaded.is_some());
+
+        // An empty slot is installed to without asking.
+        press(&mut app, &[KeyCode::Char('i')]);
+        assert!(
+            parse_persistent_game_data(&save_file_path)
+                .unwrap()
+                .get_isaac_version()
+                == Some(IsaacVersion::Repentance)
+        );
+
+        press(&mut app, &[KeyCode::Char('d'), KeyCode::Char('n')]);
+        assert!(save_file_path.exists());
+        assert_eq!(get_last_log_line(&app), "Canceled.");
+
+        press(&mut app, &[KeyCode::Char('d'), KeyCode::Char('y')]);
+        assert!(!save_file_path.exists());
+    }
+
+    #[test]
+    fn selects_other_slots_and_activities() {
+        let fake_tree = FakeTree::new();
+        let mut app = load_repentance();
+
+        press(&mut app, &[KeyCode::Up, KeyCode::Char('r')]);
+        assert_eq!(get_selected_slot(&app), 3);
+        assert!(fake_tree
+            .get_save_file_path(IsaacVersion::Repentance, 3)
+            .exists());
+
+        press(&mut app, &[KeyCode::Char('o'), KeyCode::Down]);
+        assert!(matches!(
+            handle_key_press(&mut app, KeyCode::Enter).unwrap(),
+            Some(TerminalRequest::OtherActivity(Activity::Statistics)),
+        ));
+    }
+
+    #[test]
+    fn shows_an_error_when_the_save_files_cannot_be_found() {
+        let _fake_tree = FakeTree::new();
+        let documents_save_data_path =
+            get_documents_save_data_path(IsaacVersion::Repentance).unwrap();
+        write(
+            documents_save_data_path.join(OPTIONS_INI),
+            "[Options]\nSteamCloud=1\n",
+        )
+        .unwrap();
+
+        // Without a Steam directory, the Steam Cloud save files cannot be found.
+        set_save_data_path_overrides(SaveDataPathOverrides {
+            steam_dir: None,
+            ..get_save_data_path_overrides()
+        });
+        let mut app = load_repentance();
+        assert!(app.loaded.is_none());
+        assert!(app.log.iter().any(|(log_level, line)| {
+            matches!(log_level, LogLevel::Error) && line.contains("registry.ini")
+        }));
+
+        press(&mut app, &[KeyCode::Tab, KeyCode::Char('i')]);
+        assert_eq!(get_last_log_line(&app), "Select a game first.");
+    }
+}
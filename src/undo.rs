@@ -0,0 +1,150 @@
+use crate::{
+    dry_run::{delete_file, get_success_prefix, write_file},
+    enums::Operation,
+    get_input::confirm_undo,
+    history::{
+        get_file_hash, get_history_entry_lines, read_history, read_snapshot, record_operation,
+        FileState, HistoryEntry,
+    },
+};
+use anyhow::{bail, Result};
+use colored::*;
+
+pub fn undo() -> Result<()> {
+    let history_entry = get_operation_to_undo()?;
+    let confirmed = confirm_undo(&get_history_entry_lines(&history_entry))?;
+    if !confirmed {
+        return Ok(());
+    }
+
+    undo_operation(&history_entry)?;
+
+    println!(
+        "{} the {} of:\n{}",
+        get_success_prefix("undid", "undo"),
+        history_entry.operation,
+        history_entry.destination_path.to_string().green(),
+    );
+
+    Ok(())
+}
+
+/// Undoing an operation is recorded in the history as well, and it cancels out the operation before
+/// it. Thus, undoing several times in a row goes further and further back.
+pub fn get_operation_to_undo() -> Result<HistoryEntry> {
+    let history = read_history()?;
+
+    let mut num_undone = 0;
+    for history_entry in history.into_iter().rev() {
+        match history_entry.operation {
+            // Backups do not change any save files.
+            Operation::Backup | Operation::Prune => {}
+            Operation::Undo => num_undone += 1,
+            _ if num_undone > 0 => num_undone -= 1,
+            // These change two files at once, so they cannot be undone with one snapshot.
+            Operation::Move | Operation::Swap => bail!(
+                "The last operation was a {}, which cannot be undone.",
+                history_entry.operation,
+            ),
+            _ => return Ok(history_entry),
+        }
+    }
+
+    bail!("There is nothing to undo.")
+}
+
+/// Puts the file back the way that it was before the operation. This refuses to run if the file was
+/// changed after the operation, since that change would be lost.
+pub fn undo_operation(history_entry: &HistoryEntry) -> Result<()> {
+    let path = &history_entry.destination_path;
+    if get_file_hash(path) != history_entry.hash_after {
+        bail!(
+            "The following file was changed after the {}, so it cannot be undone:\n{}",
+            history_entry.operation,
+            path.to_string().green(),
+        );
+    }
+
+    let state_before = FileState::read(path)?;
+    match &history_entry.hash_before {
+        Some(hash) => {
+            let bytes = read_snapshot(hash)?;
+            write_file(path, bytes)?;
+        }
+
+        // The file did not exist before the operation.
+        None => delete_file(path)?,
+    }
+
+    record_operation(Operation::Undo, None, path, state_before)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        enums::IsaacVersion, reset::get_new_persistent_game_data,
+        save_data_path::get_documents_save_data_path, test_utils::FakeTree,
+    };
+    use std::fs::{read, read_to_string, write};
+
+    #[test]
+    fn undoes_install() {
+        let fake_tree = FakeTree::new();
+        fake_tree
+            .run(&["y", "5", "1", "1", "15", "y", "19"])
+            .unwrap();
+
+        assert!(!fake_tree
+            .get_save_file_path(IsaacVersion::Repentance, 1)
+            .exists());
+    }
+
+    #[test]
+    fn undoes_several_operations() {
+        let fake_tree = FakeTree::new();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        let original_bytes = get_new_persistent_game_data(IsaacVersion::Repentance)
+            .unwrap()
+            .to_bytes();
+        write(&save_file_path, &original_bytes).unwrap();
+
+        // Install over the save file, delete it, and then undo both.
+        fake_tree
+            .run(&["y", "5", "1", "1", "3", "1", "15", "y", "15", "y", "19"])
+            .unwrap();
+
+        assert_eq!(read(&save_file_path).unwrap(), original_bytes);
+    }
+
+    #[test]
+    fn undoes_steam_cloud_change() {
+        let fake_tree = FakeTree::new();
+        let options_ini_path = get_documents_save_data_path(IsaacVersion::Repentance)
+            .unwrap()
+            .join("options.ini");
+        let original_options_ini = read_to_string(&options_ini_path).unwrap();
+
+        fake_tree
+            .run(&["y", "5", "4", "y", "15", "y", "19"])
+            .unwrap();
+
+        assert_eq!(
+            read_to_string(&options_ini_path).unwrap(),
+            original_options_ini,
+        );
+    }
+
+    #[test]
+    fn refuses_to_undo_changed_file() {
+        let fake_tree = FakeTree::new();
+        fake_tree.run(&["y", "5", "1", "1", "19"]).unwrap();
+
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        write(&save_file_path, b"changed by the game").unwrap();
+
+        let history_entry = get_operation_to_undo().unwrap();
+        assert!(undo_operation(&history_entry).is_err());
+        assert_eq!(read(&save_file_path).unwrap(), b"changed by the game");
+    }
+}
@@ -1,7 +1,20 @@
+use crate::{constants::SANDBOX_EXE_DIR_NAME, sandbox::get_sandbox_root};
 use anyhow::{bail, Context, Result};
 use camino::Utf8PathBuf;
+use std::fs::create_dir_all;
 
+/// Inside of a sandbox, this is a directory in the sandbox root instead, so that backups and exports
+/// stay in the sandbox.
 pub fn get_dir_of_running_exe() -> Result<Utf8PathBuf> {
+    if let Some(root) = get_sandbox_root() {
+        let dir_path = root.join(SANDBOX_EXE_DIR_NAME);
+        create_dir_all(&dir_path).context(format!(
+            "Failed to create the following directory:\n{}",
+            dir_path,
+        ))?;
+        return Ok(dir_path);
+    }
+
     let exe_path =
         std::env::current_exe().context("Failed to get the path of the current executable.")?;
     let exe_path_utf8_result = Utf8PathBuf::from_path_buf(exe_path);
@@ -0,0 +1,187 @@
+use crate::{
+    backup_store::{get_backups_dir, prune_backups, store_backup, BackupEntry, RetentionPolicy},
+    dry_run::print_dry_run_actions,
+    enums::IsaacVersion,
+    errors::{ErrorKind, InstallerError},
+    history::{format_timestamp, get_timestamp},
+    isaac_save_installer::{get_existing_save_files, is_isaac_open},
+    print_error,
+    save_data_path::{
+        get_documents_save_data_path, get_steam_cloud_enabled, get_steam_save_data_path,
+    },
+};
+use anyhow::{bail, Result};
+use camino::Utf8PathBuf;
+use colored::*;
+use std::{thread::sleep, time::Duration};
+use sysinfo::{System, SystemExt};
+
+const WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Backs up the save files every time that the game is closed, so that progress that is lost (e.g.
+/// to a Steam Cloud rollback) can be brought back. This runs until the program is stopped.
+pub fn watch(retention_policy: &RetentionPolicy) -> Result<()> {
+    let game_found = (0..)
+        .map_while(IsaacVersion::from_repr)
+        .any(|isaac_version| get_documents_save_data_path(isaac_version).is_ok());
+    if !game_found {
+        bail!(InstallerError::new(
+            ErrorKind::SaveDirNotFound,
+            "Failed to find the save files for any of the games.",
+        ));
+    }
+
+    println!("Watching for the game to close, so that your save files can be backed up after each session.");
+    println!("The backups are kept in the following directory:");
+    println!("{}", get_backups_dir()?.to_string().green());
+    println!(
+        "For each save file, the backups that are kept are {}.",
+        retention_policy,
+    );
+    println!("Press Ctrl+C to stop.");
+    println!();
+
+    let mut system = System::new();
+    let mut isaac_open = is_isaac_open(&mut system);
+
+    // The save files might be in the middle of being written to while the game is open.
+    match isaac_open {
+        true => print_watch_message("The game is open."),
+        false => back_up_and_print(retention_policy),
+    }
+
+    loop {
+        sleep(WATCH_INTERVAL);
+
+        match (isaac_open, is_isaac_open(&mut system)) {
+            (false, true) => {
+                print_watch_message("The game was opened.");
+                isaac_open = true;
+            }
+            (true, false) => {
+                print_watch_message("The game was closed.");
+                back_up_and_print(retention_policy);
+                isaac_open = false;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A failed backup should not stop the next one, so the error is only printed.
+fn back_up_and_print(retention_policy: &RetentionPolicy) {
+    match back_up_changed_save_files(retention_policy) {
+        Ok(backup_entries) if backup_entries.is_empty() => {
+            println!("None of the save files were changed.");
+        }
+        Ok(backup_entries) => {
+            for backup_entry in &backup_entries {
+                println!(
+                    "Backed up:\n{}",
+                    backup_entry.source_path.to_string().green(),
+                );
+            }
+        }
+        Err(err) => print_error(&err),
+    }
+    print_dry_run_actions();
+    println!();
+}
+
+fn print_watch_message(message: &str) {
+    let timestamp = get_timestamp().map(format_timestamp).unwrap_or_default();
+    println!("[{}] {}", timestamp, message);
+}
+
+/// Returns the new backups. The save files of every game are backed up, since any of them might have
+/// been played.
+pub fn back_up_changed_save_files(retention_policy: &RetentionPolicy) -> Result<Vec<BackupEntry>> {
+    let mut backup_entries = Vec::new();
+    for save_file_path in get_active_save_files() {
+        if let Some(backup_entry) = store_backup(&save_file_path)? {
+            backup_entries.push(backup_entry);
+        }
+    }
+    prune_backups(retention_policy, get_timestamp()?)?;
+
+    Ok(backup_entries)
+}
+
+/// The save files that the game reads from, which depends on the "SteamCloud" setting. Games that are
+/// not installed are skipped.
+fn get_active_save_files() -> Vec<Utf8PathBuf> {
+    let mut save_file_paths: Vec<Utf8PathBuf> = Vec::new();
+    for isaac_version in (0..).map_while(IsaacVersion::from_repr) {
+        let Ok(documents_save_data_path) = get_documents_save_data_path(isaac_version) else {
+            continue;
+        };
+        let Ok(steam_cloud_enabled) = get_steam_cloud_enabled(&documents_save_data_path) else {
+            continue;
+        };
+        let save_data_path = match steam_cloud_enabled {
+            true => match get_steam_save_data_path() {
+                Ok(steam_save_data_path) => steam_save_data_path,
+                Err(_) => continue,
+            },
+            false => documents_save_data_path,
+        };
+
+        // Afterbirth+ and Booster Pack 5 use the same save files.
+        for (save_file_path, exists) in
+            get_existing_save_files(isaac_version, &save_data_path, steam_cloud_enabled)
+        {
+            if exists && !save_file_paths.contains(&save_file_path) {
+                save_file_paths.push(save_file_path);
+            }
+        }
+    }
+
+    save_file_paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        backup_store::{get_backup_object_path, read_backup_index},
+        history::get_hash,
+        test_utils::FakeTree,
+    };
+    use std::fs::{read, write};
+
+    #[test]
+    fn watch_backs_up_changed_save_files() {
+        let fake_tree = FakeTree::new();
+        fake_tree.run(&["y", "5", "1", "1", "19"]).unwrap();
+        let save_file_path = fake_tree.get_save_file_path(IsaacVersion::Repentance, 1);
+        let save_file_bytes = read(&save_file_path).unwrap();
+        let retention_policy = RetentionPolicy {
+            keep_last: 2,
+            keep_daily: Some(0),
+            keep_weekly: Some(0),
+        };
+
+        let backup_entries = back_up_changed_save_files(&retention_policy).unwrap();
+        assert_eq!(backup_entries.len(), 1);
+        assert_eq!(backup_entries[0].save_file_slot, Some(1));
+        assert!(backup_entries[0].isaac_version == Some(IsaacVersion::Repentance));
+
+        // Nothing changed, so there is nothing to back up.
+        assert!(back_up_changed_save_files(&retention_policy)
+            .unwrap()
+            .is_empty());
+
+        // Only the newest two backups are kept, and the contents that are no longer used are deleted.
+        write(&save_file_path, b"first session").unwrap();
+        back_up_changed_save_files(&retention_policy).unwrap();
+        write(&save_file_path, b"second session").unwrap();
+        back_up_changed_save_files(&retention_policy).unwrap();
+
+        let backup_entries = read_backup_index().unwrap();
+        assert_eq!(backup_entries.len(), 2);
+        assert_eq!(backup_entries[1].hash, get_hash(b"second session"));
+        assert!(!get_backup_object_path(&get_hash(&save_file_bytes))
+            .unwrap()
+            .exists());
+    }
+}